@@ -0,0 +1,209 @@
+//! Aggregates of a file answered from its metadata, without reading any page, e.g. for
+//! `COUNT(*)` or a summary of the columns.
+use crate::metadata::{ParquetMetaData, Statistics, ValueStatistics};
+use crate::predicate::ScalarValue;
+
+/// The answer to an aggregate from the metadata of a file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataAnswer<T> {
+    /// The actual value of the aggregate.
+    Exact(T),
+    /// A bound of the actual value, e.g. when a writer truncated long strings in the
+    /// statistics: a lower bound of a minimum, an upper bound of a maximum.
+    Inexact(T),
+    /// Statistics are missing or cannot be trusted, the pages must be decoded.
+    NeedsDecoding,
+}
+
+impl<T> MetadataAnswer<T> {
+    /// Returns the value if it is exact.
+    pub fn exact(self) -> Option<T> {
+        match self {
+            MetadataAnswer::Exact(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the number of rows of the file, which is always known.
+pub fn row_count(metadata: &ParquetMetaData) -> u64 {
+    metadata.row_groups.iter().map(|rg| rg.num_rows).sum()
+}
+
+/// Returns the number of nulls of leaf column `column` in the file.
+///
+/// The answers of the functions of this module are [`MetadataAnswer::NeedsDecoding`], or
+/// `None`, for a `column` that is not in the file.
+pub fn null_count(metadata: &ParquetMetaData, column: usize) -> MetadataAnswer<usize> {
+    let Some(descr) = metadata.file_metadata.schema_descr.columns().get(column) else {
+        return MetadataAnswer::NeedsDecoding;
+    };
+    if descr.max_def_level() == 0 {
+        return MetadataAnswer::Exact(0);
+    }
+    let null_counts = metadata.row_groups.iter().map(|rg| {
+        let chunk = rg.columns.get(column)?;
+        match chunk.statistics() {
+            Some(statistics) => statistics.null_count(),
+            None if chunk.num_values() == 0 => Some(0),
+            None => None,
+        }
+    });
+    match null_counts.sum::<Option<usize>>() {
+        Some(null_count) => MetadataAnswer::Exact(null_count),
+        None => MetadataAnswer::NeedsDecoding,
+    }
+}
+
+/// Returns the minimum of leaf column `column` in the file, `None` if all its values
+/// are null.
+pub fn min_value(metadata: &ParquetMetaData, column: usize) -> MetadataAnswer<Option<ScalarValue>> {
+    fold_bounds(metadata, column, false)
+}
+
+/// Returns the maximum of leaf column `column` in the file, `None` if all its values
+/// are null.
+pub fn max_value(metadata: &ParquetMetaData, column: usize) -> MetadataAnswer<Option<ScalarValue>> {
+    fold_bounds(metadata, column, true)
+}
+
+/// Returns the statistics of leaf column `column` over the whole file, merged from the
+/// statistics of its row groups. `None` if a row group has none.
+///
+/// The statistics of files of a dataset can be merged further with [`Statistics::merge`].
+pub fn column_statistics(metadata: &ParquetMetaData, column: usize) -> Option<Statistics> {
+    if column >= metadata.file_metadata.schema_descr.num_columns() {
+        return None;
+    }
+    let mut merged: Option<Statistics> = None;
+    // Column chunks of only nulls have no min and max, which is not "unknown".
+    let mut nulls_only: Option<Statistics> = None;
+    for rg in &metadata.row_groups {
+        let chunk = rg.columns.get(column)?;
+        if chunk.num_values() == 0 {
+            continue;
+        }
+        let statistics = chunk.statistics()?;
+        let target = if statistics.null_count() == Some(chunk.num_values()) {
+            &mut nulls_only
+        } else {
+            &mut merged
+        };
+        match target {
+            Some(target) => target.merge(statistics, chunk.sort_order()).ok()?,
+            None => *target = Some(statistics.clone()),
+        }
+    }
+    match (merged, nulls_only) {
+        (Some(mut merged), Some(nulls_only)) => {
+            merged.add_null_count(nulls_only.null_count());
+            Some(merged)
+        }
+        (merged, nulls_only) => merged.or(nulls_only),
+    }
+}
+
+fn fold_bounds(
+    metadata: &ParquetMetaData,
+    column: usize,
+    max: bool,
+) -> MetadataAnswer<Option<ScalarValue>> {
+    if column >= metadata.file_metadata.schema_descr.num_columns() {
+        return MetadataAnswer::NeedsDecoding;
+    }
+    let num_values = metadata
+        .row_groups
+        .iter()
+        .map(|rg| rg.columns.get(column).map(|chunk| chunk.num_values()))
+        .sum::<Option<usize>>();
+    let Some(num_values) = num_values else {
+        return MetadataAnswer::NeedsDecoding;
+    };
+    if num_values == 0 {
+        return MetadataAnswer::Exact(None);
+    }
+    let Some(statistics) = column_statistics(metadata, column) else {
+        return MetadataAnswer::NeedsDecoding;
+    };
+    if statistics.null_count() == Some(num_values) {
+        return MetadataAnswer::Exact(None);
+    }
+    match bound(&statistics, max) {
+        Some((value, true)) => MetadataAnswer::Exact(Some(value)),
+        Some((value, false)) => MetadataAnswer::Inexact(Some(value)),
+        None => MetadataAnswer::NeedsDecoding,
+    }
+}
+
+/// Returns the maximum, or the minimum, of `statistics` and whether it is exact. `None`
+/// if it was not written or has no [`ScalarValue`].
+fn bound(statistics: &Statistics, max: bool) -> Option<(ScalarValue, bool)> {
+    fn bound<T: Clone>(
+        stats: &ValueStatistics<T>,
+        max: bool,
+        to_scalar: impl Fn(T) -> ScalarValue,
+    ) -> Option<(ScalarValue, bool)> {
+        let (value, exact) = if max {
+            (stats.max(), stats.is_max_value_exact())
+        } else {
+            (stats.min(), stats.is_min_value_exact())
+        };
+        value.cloned().map(|value| (to_scalar(value), exact))
+    }
+
+    match statistics {
+        Statistics::Boolean(s) => bound(s, max, ScalarValue::Boolean),
+        Statistics::Int32(s) => bound(s, max, ScalarValue::Int32),
+        Statistics::Int64(s) => bound(s, max, ScalarValue::Int64),
+        Statistics::Float(s) => bound(s, max, ScalarValue::Float),
+        Statistics::Double(s) => bound(s, max, ScalarValue::Double),
+        Statistics::ByteArray(s) => bound(s, max, ScalarValue::ByteArray),
+        Statistics::FixedLenByteArray(s) => bound(s, max, ScalarValue::FixedLenByteArray),
+        // The sort order of INT96 is undefined.
+        Statistics::Int96(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::get_metadata;
+    use crate::test_util::{write_file, TestColumn, TestValues};
+
+    #[test]
+    fn test_metadata_answers() {
+        let a = vec![vec![Some(3), None, Some(-2)], vec![Some(7), None, None]];
+        let b = vec![vec![None::<Vec<u8>>; 3], vec![None; 3]];
+        let c = vec![(0..6).map(|i| Some(i as f64)).collect()];
+        let file = write_file(vec![
+            TestColumn::new("a", true, TestValues::Int32(a)),
+            TestColumn::new("b", true, TestValues::ByteArray(b)),
+            TestColumn::new("c", false, TestValues::Double(c)),
+        ]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+
+        assert_eq!(row_count(&metadata), 6);
+        assert_eq!(null_count(&metadata, 0), MetadataAnswer::Exact(3));
+        assert_eq!(null_count(&metadata, 1), MetadataAnswer::Exact(6));
+        assert_eq!(null_count(&metadata, 2), MetadataAnswer::Exact(0));
+
+        assert_eq!(min_value(&metadata, 0).exact(), Some(Some((-2).into())));
+        assert_eq!(max_value(&metadata, 0).exact(), Some(Some(7.into())));
+        assert_eq!(max_value(&metadata, 1), MetadataAnswer::Exact(None));
+        assert_eq!(max_value(&metadata, 2).exact(), Some(Some(5f64.into())));
+        // The +0.0 min of `c` is read as -0.0, which is not in the column.
+        assert_eq!(
+            min_value(&metadata, 2),
+            MetadataAnswer::Inexact(Some((-0.0f64).into()))
+        );
+
+        let statistics = column_statistics(&metadata, 1).unwrap();
+        assert_eq!(statistics.null_count(), Some(6));
+
+        // A column that is not in the file.
+        assert_eq!(null_count(&metadata, 3), MetadataAnswer::NeedsDecoding);
+        assert_eq!(min_value(&metadata, 3), MetadataAnswer::NeedsDecoding);
+        assert_eq!(max_value(&metadata, 3), MetadataAnswer::NeedsDecoding);
+        assert_eq!(column_statistics(&metadata, 3), None);
+    }
+}
@@ -0,0 +1,289 @@
+//! Split block bloom filters (SBBF), as written by Parquet writers for column chunks.
+//!
+//! See the [specification](https://github.com/apache/parquet-format/blob/master/BloomFilter.md).
+use crate::errors::{ParquetError, ParquetResult};
+use crate::metadata::{ColumnChunkMetaData, TBloomFilterHeader};
+use crate::physical::{ByteArray, FixedLenByteArray, Int96};
+use crate::reader::ParquetReader;
+use thrift::protocol::{TCompactInputProtocol, TSerializable};
+use xxhash_rust::xxh64::xxh64;
+
+/// Salt values used to set the bits of a block, from the specification.
+const SALT: [u32; 8] = [
+    0x47b6137b, 0x44974d91, 0x8824ad5b, 0xa2b7289d, 0x705495c7, 0x2df1424b, 0x9efc4947, 0x5c6bfb31,
+];
+
+/// Each block is 256 bits, stored as 8 little endian `u32` words.
+type Block = [u32; 8];
+
+fn block_mask(key: u32) -> Block {
+    let mut mask = [0; 8];
+    for (word, salt) in mask.iter_mut().zip(SALT) {
+        *word = 1 << (key.wrapping_mul(salt) >> 27);
+    }
+    mask
+}
+
+/// A split block bloom filter of a column chunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sbbf {
+    blocks: Vec<Block>,
+}
+
+impl Sbbf {
+    /// Creates a filter from its bitset, of which the length must be a multiple of 32.
+    pub fn new(bitset: &[u8]) -> ParquetResult<Self> {
+        if bitset.is_empty() || !bitset.len().is_multiple_of(32) {
+            return Err(ParquetError::InvalidFormat(format!(
+                "The bitset of a bloom filter should be a non-zero multiple of 32 bytes, found {}",
+                bitset.len()
+            )));
+        }
+        let blocks = bitset
+            .chunks_exact(32)
+            .map(|chunk| {
+                let mut block = [0; 8];
+                for (word, bytes) in block.iter_mut().zip(chunk.chunks_exact(4)) {
+                    *word = u32::from_le_bytes(bytes.try_into().unwrap());
+                }
+                block
+            })
+            .collect();
+        Ok(Sbbf { blocks })
+    }
+
+    fn block_index(&self, hash: u64) -> usize {
+        (((hash >> 32) * self.blocks.len() as u64) >> 32) as usize
+    }
+
+    /// Whether a value with xxHash64 `hash` may be in the column chunk.
+    pub fn check_hash(&self, hash: u64) -> bool {
+        let block = &self.blocks[self.block_index(hash)];
+        let mask = block_mask(hash as u32);
+        block.iter().zip(mask).all(|(word, mask)| word & mask != 0)
+    }
+
+    /// Whether `value` may be in the column chunk. If `false`, it is certainly not.
+    ///
+    /// The type of `value` must be the physical type of the column.
+    pub fn check<T: BloomFilterValue + ?Sized>(&self, value: &T) -> bool {
+        self.check_hash(value.bloom_filter_hash())
+    }
+
+    /// Adds a value with xxHash64 `hash`.
+    #[cfg(test)]
+    pub(crate) fn insert_hash(&mut self, hash: u64) {
+        let i = self.block_index(hash);
+        let mask = block_mask(hash as u32);
+        for (word, mask) in self.blocks[i].iter_mut().zip(mask) {
+            *word |= mask;
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        self.blocks
+            .iter()
+            .flatten()
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+}
+
+/// A value that can be looked up in a bloom filter. Values are hashed with xxHash64 of
+/// their PLAIN encoding, without the length prefix for byte arrays.
+pub trait BloomFilterValue {
+    fn bloom_filter_hash(&self) -> u64;
+}
+
+macro_rules! native_bloom_filter_value {
+    ($type:ty) => {
+        impl BloomFilterValue for $type {
+            fn bloom_filter_hash(&self) -> u64 {
+                xxh64(&self.to_le_bytes(), 0)
+            }
+        }
+    };
+}
+
+native_bloom_filter_value!(i32);
+native_bloom_filter_value!(i64);
+native_bloom_filter_value!(f32);
+native_bloom_filter_value!(f64);
+
+impl BloomFilterValue for bool {
+    fn bloom_filter_hash(&self) -> u64 {
+        xxh64(&[*self as u8], 0)
+    }
+}
+
+impl BloomFilterValue for Int96 {
+    fn bloom_filter_hash(&self) -> u64 {
+        let mut bytes = [0u8; 12];
+        for (chunk, word) in bytes.chunks_exact_mut(4).zip(self.data()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        xxh64(&bytes, 0)
+    }
+}
+
+impl BloomFilterValue for [u8] {
+    fn bloom_filter_hash(&self) -> u64 {
+        xxh64(self, 0)
+    }
+}
+
+impl BloomFilterValue for str {
+    fn bloom_filter_hash(&self) -> u64 {
+        xxh64(self.as_bytes(), 0)
+    }
+}
+
+impl BloomFilterValue for ByteArray {
+    fn bloom_filter_hash(&self) -> u64 {
+        xxh64(self.data(), 0)
+    }
+}
+
+impl BloomFilterValue for FixedLenByteArray {
+    fn bloom_filter_hash(&self) -> u64 {
+        xxh64(self.data(), 0)
+    }
+}
+
+/// An upper bound of the size of a bloom filter header, which only has a number and three
+/// unions of empty structs.
+const HEADER_SIZE_ESTIMATE: usize = 32;
+
+/// Reads the bloom filter of a column chunk, `None` if it has none or if this reader
+/// cannot use it, e.g. an algorithm, hash or compression added to the format later.
+///
+/// Files written before parquet-format 2.10 have no `bloom_filter_length`, the header is
+/// then read from the first bytes of the filter, and the bitset after it.
+pub fn read_bloom_filter<R: ParquetReader>(
+    reader: R,
+    column: &ColumnChunkMetaData,
+) -> ParquetResult<Option<Sbbf>> {
+    let Some(offset) = column.bloom_filter_offset() else {
+        return Ok(None);
+    };
+    let length = match column.bloom_filter_length() {
+        Some(length) => length,
+        None => reader
+            .len()
+            .checked_sub(offset)
+            .ok_or(ParquetError::EOF)?
+            .min(HEADER_SIZE_ESTIMATE),
+    };
+
+    let prefix = reader.get_bytes(offset, length)?;
+    let mut bytes = prefix;
+    let mut protocol = TCompactInputProtocol::new(&mut bytes);
+    // The algorithm, hash and compression unions fail to parse with a member that is not
+    // split blocks, xxHash64 and uncompressed.
+    let Ok(header) = TBloomFilterHeader::read_from_in_protocol(&mut protocol) else {
+        return Ok(None);
+    };
+    let num_bytes: usize = header.num_bytes.try_into().map_err(|_| {
+        ParquetError::InvalidFormat("Negative 'num_bytes' found in bloom filter header".into())
+    })?;
+    let bitset = match column.bloom_filter_length() {
+        Some(_) => bytes.get(..num_bytes).ok_or(ParquetError::EOF)?,
+        None => reader.get_bytes(offset + prefix.len() - bytes.len(), num_bytes)?,
+    };
+    Sbbf::new(bitset).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::get_metadata;
+    use crate::test_util::{write_file, TestColumn, TestValues};
+
+    #[test]
+    fn test_check() {
+        let mut sbbf = Sbbf::new(&[0; 64]).unwrap();
+        for i in 0..20i64 {
+            sbbf.insert_hash((i * 7).bloom_filter_hash());
+        }
+        sbbf.insert_hash("parquet".bloom_filter_hash());
+
+        assert!((0..20i64).all(|i| sbbf.check(&(i * 7))));
+        assert!(sbbf.check("parquet"));
+        assert!(sbbf.check(&ByteArray::new(b"parquet".to_vec())));
+        assert!(!sbbf.check("arrow"));
+
+        let sbbf = Sbbf::new(&sbbf.to_bytes()).unwrap();
+        assert!(sbbf.check(&14i64));
+    }
+
+    #[test]
+    fn test_reference_filter() {
+        // xxHash64 with seed 0 of the empty input, from the xxHash reference implementation.
+        assert_eq!("".bloom_filter_hash(), 0xEF46_DB37_51D8_E999);
+
+        // Written by parquet-mr for the BYTE_ARRAY values "a0" to "a9".
+        let bitset: &[u8] = &[
+            200, 1, 80, 20, 64, 68, 8, 109, 6, 37, 4, 67, 144, 80, 96, 32, 8, 132, 43, 33, 0, 5,
+            99, 65, 2, 0, 224, 44, 64, 78, 96, 4,
+        ];
+        let mut sbbf = Sbbf::new(&[0; 32]).unwrap();
+        for i in 0..10 {
+            sbbf.insert_hash(format!("a{i}").bloom_filter_hash());
+        }
+        assert_eq!(sbbf.to_bytes(), bitset);
+        assert_eq!(Sbbf::new(bitset).unwrap(), sbbf);
+    }
+
+    #[test]
+    fn test_read_bloom_filter() {
+        let ids = vec![(0..100i64).map(|i| Some(i * 2)).collect()];
+        let mut id = TestColumn::new("id", false, TestValues::Int64(ids));
+        id.bloom_filter = true;
+        let names = vec![vec![Some(b"parquet".to_vec())]];
+        let name = TestColumn::new("name", false, TestValues::ByteArray(names));
+        let file = write_file(vec![id, name]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let columns = &metadata.row_groups[0].columns;
+
+        let sbbf = read_bloom_filter(file.as_slice(), &columns[0])
+            .unwrap()
+            .unwrap();
+        assert_eq!(sbbf.to_bytes().len(), 128);
+        assert!((0..100i64).all(|i| sbbf.check(&(i * 2))));
+        assert!(!sbbf.check(&51i64));
+        assert_eq!(
+            read_bloom_filter(file.as_slice(), &columns[1]).unwrap(),
+            None
+        );
+
+        let offset = columns[0].bloom_filter_offset().unwrap();
+        let truncated = &file[..offset + 64];
+        assert!(read_bloom_filter(truncated, &columns[0]).is_err());
+
+        // An algorithm other than split blocks, the union member after `num_bytes`.
+        let mut unknown = file.clone();
+        assert_eq!(unknown[offset + 4], 0x1C);
+        unknown[offset + 4] = 0x2C;
+        assert_eq!(
+            read_bloom_filter(unknown.as_slice(), &columns[0]).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_read_bloom_filter_without_length() {
+        let ids = vec![(0..100i64).map(|i| Some(i * 2)).collect()];
+        let mut id = TestColumn::new("id", false, TestValues::Int64(ids));
+        id.bloom_filter = true;
+        id.bloom_filter_length = false;
+        let file = write_file(vec![id]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let column = &metadata.row_groups[0].columns[0];
+        assert_eq!(column.bloom_filter_length(), None);
+
+        let sbbf = read_bloom_filter(file.as_slice(), column).unwrap().unwrap();
+        assert_eq!(sbbf.to_bytes().len(), 128);
+        assert!((0..100i64).all(|i| sbbf.check(&(i * 2))));
+    }
+}
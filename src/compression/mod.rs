@@ -1,7 +1,7 @@
 #[cfg(feature = "zstd")]
 mod codec_zstd;
 
-use crate::errors::ParquetResult;
+use crate::errors::{ParquetError, ParquetResult};
 use crate::metadata::Compression;
 
 pub(crate) trait Decompressor: Send {
@@ -20,12 +20,19 @@ pub(crate) trait Decompressor: Send {
     ) -> ParquetResult<usize>;
 }
 
-pub(crate) fn create_decompressor(compression: Compression) -> Option<Box<dyn Decompressor>> {
+/// Returns the decompressor of a codec, `None` for uncompressed data.
+///
+/// Fails for the codecs that are unknown or not enabled with a feature.
+pub(crate) fn create_decompressor(
+    compression: Compression,
+) -> ParquetResult<Option<Box<dyn Decompressor>>> {
     use Compression::*;
     match compression {
         #[cfg(feature = "zstd")]
-        Zstd => Some(Box::new(codec_zstd::ZstdDecompressor {})),
-        Uncompressed => None,
-        _ => todo!(),
+        Zstd => Ok(Some(Box::new(codec_zstd::ZstdDecompressor {}))),
+        Uncompressed => Ok(None),
+        other => Err(ParquetError::NotSupported(format!(
+            "Decompressing {other:?} compressed pages"
+        ))),
     }
 }
@@ -0,0 +1,630 @@
+use crate::data::page::Page;
+use crate::data::selection::RowSelection;
+use crate::encoding::rle::decode_hybrid_rle;
+use crate::encoding::{bit_width, plain, split_length_prefixed};
+use crate::errors::{ParquetError, ParquetResult};
+use crate::metadata::types::ColumnDescriptor;
+use crate::metadata::{Encoding, PhysicalType};
+use crate::physical::{ByteArray, FixedLenByteArray, Int96};
+
+/// The decoded values of a column, without nulls.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnValues {
+    Boolean(Vec<bool>),
+    Int32(Vec<i32>),
+    Int64(Vec<i64>),
+    Int96(Vec<Int96>),
+    Float(Vec<f32>),
+    Double(Vec<f64>),
+    ByteArray(Vec<ByteArray>),
+    FixedLenByteArray(Vec<FixedLenByteArray>),
+}
+
+macro_rules! with_column_values {
+    ($values:expr, $v:ident => $e:expr) => {
+        match $values {
+            ColumnValues::Boolean($v) => $e,
+            ColumnValues::Int32($v) => $e,
+            ColumnValues::Int64($v) => $e,
+            ColumnValues::Int96($v) => $e,
+            ColumnValues::Float($v) => $e,
+            ColumnValues::Double($v) => $e,
+            ColumnValues::ByteArray($v) => $e,
+            ColumnValues::FixedLenByteArray($v) => $e,
+        }
+    };
+}
+
+impl ColumnValues {
+    pub fn new_empty(physical_type: PhysicalType) -> Self {
+        match physical_type {
+            PhysicalType::Boolean => ColumnValues::Boolean(vec![]),
+            PhysicalType::Int32 => ColumnValues::Int32(vec![]),
+            PhysicalType::Int64 => ColumnValues::Int64(vec![]),
+            PhysicalType::Int96 => ColumnValues::Int96(vec![]),
+            PhysicalType::Float => ColumnValues::Float(vec![]),
+            PhysicalType::Double => ColumnValues::Double(vec![]),
+            PhysicalType::ByteArray => ColumnValues::ByteArray(vec![]),
+            PhysicalType::FixedLenByteArray => ColumnValues::FixedLenByteArray(vec![]),
+        }
+    }
+
+    pub fn physical_type(&self) -> PhysicalType {
+        match self {
+            ColumnValues::Boolean(_) => PhysicalType::Boolean,
+            ColumnValues::Int32(_) => PhysicalType::Int32,
+            ColumnValues::Int64(_) => PhysicalType::Int64,
+            ColumnValues::Int96(_) => PhysicalType::Int96,
+            ColumnValues::Float(_) => PhysicalType::Float,
+            ColumnValues::Double(_) => PhysicalType::Double,
+            ColumnValues::ByteArray(_) => PhysicalType::ByteArray,
+            ColumnValues::FixedLenByteArray(_) => PhysicalType::FixedLenByteArray,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        with_column_values!(self, v => v.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Decodes the values of a column of the FLOAT16 logical type, which are stored as
+    /// 2 byte fixed length byte arrays.
+    pub fn decode_float16(&self) -> ParquetResult<Vec<f32>> {
+        let ColumnValues::FixedLenByteArray(values) = self else {
+            return Err(ParquetError::InvalidFormat(format!(
+                "Expected FIXED_LEN_BYTE_ARRAY values for FLOAT16, found {:?}",
+                self.physical_type()
+            )));
+        };
+        values
+            .iter()
+            .map(|value| {
+                value.as_float16().ok_or_else(|| {
+                    ParquetError::InvalidFormat(format!(
+                        "Expected 2 bytes for a FLOAT16 value, found {}",
+                        value.data().len()
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Appends the values of `other`, which must have the same physical type.
+    pub(crate) fn append(&mut self, other: ColumnValues) -> ParquetResult<()> {
+        use ColumnValues::*;
+        match (&mut *self, other) {
+            (Boolean(l), Boolean(r)) => l.extend(r),
+            (Int32(l), Int32(r)) => l.extend(r),
+            (Int64(l), Int64(r)) => l.extend(r),
+            (Int96(l), Int96(r)) => l.extend(r),
+            (Float(l), Float(r)) => l.extend(r),
+            (Double(l), Double(r)) => l.extend(r),
+            (ByteArray(l), ByteArray(r)) => l.extend(r),
+            (FixedLenByteArray(l), FixedLenByteArray(r)) => l.extend(r),
+            (_, other) => {
+                return Err(ParquetError::InvalidFormat(format!(
+                    "Expected {:?} values, found {:?}",
+                    self.physical_type(),
+                    other.physical_type()
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Keeps the values for which `keep` is `true`.
+    pub(crate) fn filter(self, keep: &[bool]) -> Self {
+        fn filter<T>(values: Vec<T>, keep: &[bool]) -> Vec<T> {
+            values
+                .into_iter()
+                .zip(keep)
+                .filter_map(|(value, keep)| keep.then_some(value))
+                .collect()
+        }
+        match self {
+            ColumnValues::Boolean(v) => ColumnValues::Boolean(filter(v, keep)),
+            ColumnValues::Int32(v) => ColumnValues::Int32(filter(v, keep)),
+            ColumnValues::Int64(v) => ColumnValues::Int64(filter(v, keep)),
+            ColumnValues::Int96(v) => ColumnValues::Int96(filter(v, keep)),
+            ColumnValues::Float(v) => ColumnValues::Float(filter(v, keep)),
+            ColumnValues::Double(v) => ColumnValues::Double(filter(v, keep)),
+            ColumnValues::ByteArray(v) => ColumnValues::ByteArray(filter(v, keep)),
+            ColumnValues::FixedLenByteArray(v) => ColumnValues::FixedLenByteArray(filter(v, keep)),
+        }
+    }
+
+    /// Returns the values at `indices`, used to look up dictionary encoded values.
+    pub(crate) fn gather(&self, indices: &[u32]) -> ParquetResult<Self> {
+        fn gather<T: Clone>(values: &[T], indices: &[u32]) -> ParquetResult<Vec<T>> {
+            indices
+                .iter()
+                .map(|i| {
+                    values.get(*i as usize).cloned().ok_or_else(|| {
+                        ParquetError::InvalidFormat(format!(
+                            "Dictionary index {i} is out of bounds for a dictionary of {} values",
+                            values.len()
+                        ))
+                    })
+                })
+                .collect()
+        }
+        let out = match self {
+            ColumnValues::Boolean(v) => ColumnValues::Boolean(gather(v, indices)?),
+            ColumnValues::Int32(v) => ColumnValues::Int32(gather(v, indices)?),
+            ColumnValues::Int64(v) => ColumnValues::Int64(gather(v, indices)?),
+            ColumnValues::Int96(v) => ColumnValues::Int96(gather(v, indices)?),
+            ColumnValues::Float(v) => ColumnValues::Float(gather(v, indices)?),
+            ColumnValues::Double(v) => ColumnValues::Double(gather(v, indices)?),
+            ColumnValues::ByteArray(v) => ColumnValues::ByteArray(gather(v, indices)?),
+            ColumnValues::FixedLenByteArray(v) => {
+                ColumnValues::FixedLenByteArray(gather(v, indices)?)
+            }
+        };
+        Ok(out)
+    }
+}
+
+/// The decoded values of a column chunk, or of the selected rows of it.
+///
+/// Like in the file, nulls and nesting are described by the definition and repetition
+/// levels: `values` only holds the values of the levels equal to the max definition level.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnData {
+    pub values: ColumnValues,
+    /// `None` if the max definition level of the column is 0, i.e. there are no nulls.
+    pub def_levels: Option<Vec<i16>>,
+    /// `None` if the max repetition level of the column is 0, i.e. it is not nested in a list.
+    pub rep_levels: Option<Vec<i16>>,
+    /// Number of rows, a row starts at every repetition level of 0.
+    pub num_rows: usize,
+}
+
+impl ColumnData {
+    pub(crate) fn new_empty(descr: &ColumnDescriptor) -> Self {
+        ColumnData {
+            values: ColumnValues::new_empty(descr.physical_type()),
+            def_levels: (descr.max_def_level() > 0).then(Vec::new),
+            rep_levels: (descr.max_rep_level() > 0).then(Vec::new),
+            num_rows: 0,
+        }
+    }
+}
+
+/// The levels and values of a single data page.
+struct DecodedPage {
+    values: ColumnValues,
+    def_levels: Option<Vec<i16>>,
+    rep_levels: Option<Vec<i16>>,
+}
+
+fn decode_levels(data: &[u8], max_level: i16, num_values: usize) -> ParquetResult<Vec<i16>> {
+    let levels = decode_hybrid_rle(data, bit_width(max_level as u64), num_values)?;
+    levels
+        .into_iter()
+        .map(|level| {
+            if level > max_level as u32 {
+                Err(ParquetError::InvalidFormat(format!(
+                    "Level {level} is larger than the max level {max_level}"
+                )))
+            } else {
+                Ok(level as i16)
+            }
+        })
+        .collect()
+}
+
+/// Decodes the levels of a v1 data page, returns them and the remaining bytes.
+fn decode_levels_v1(
+    data: &[u8],
+    encoding: Encoding,
+    max_level: i16,
+    num_values: usize,
+) -> ParquetResult<(Option<Vec<i16>>, &[u8])> {
+    if max_level == 0 {
+        return Ok((None, data));
+    }
+    if encoding != Encoding::RLE {
+        return Err(ParquetError::NotSupported(format!(
+            "Decoding {encoding:?} encoded levels"
+        )));
+    }
+    let (levels, rest) = split_length_prefixed(data)?;
+    Ok((Some(decode_levels(levels, max_level, num_values)?), rest))
+}
+
+fn decode_plain(
+    data: &[u8],
+    descr: &ColumnDescriptor,
+    num_values: usize,
+) -> ParquetResult<ColumnValues> {
+    let values = match descr.physical_type() {
+        PhysicalType::Boolean => ColumnValues::Boolean(plain::decode_bool(data, num_values)?),
+        PhysicalType::Int32 => ColumnValues::Int32(plain::decode_native(data, num_values)?),
+        PhysicalType::Int64 => ColumnValues::Int64(plain::decode_native(data, num_values)?),
+        PhysicalType::Int96 => ColumnValues::Int96(plain::decode_int96(data, num_values)?),
+        PhysicalType::Float => ColumnValues::Float(plain::decode_native(data, num_values)?),
+        PhysicalType::Double => ColumnValues::Double(plain::decode_native(data, num_values)?),
+        PhysicalType::ByteArray => {
+            ColumnValues::ByteArray(plain::decode_byte_array(data, num_values)?)
+        }
+        PhysicalType::FixedLenByteArray => {
+            let type_length = descr.type_length().try_into().map_err(|_| {
+                ParquetError::InvalidFormat(
+                    "FIXED_LEN_BYTE_ARRAY column without a type length".into(),
+                )
+            })?;
+            ColumnValues::FixedLenByteArray(plain::decode_fixed_len_byte_array(
+                data,
+                type_length,
+                num_values,
+            )?)
+        }
+    };
+    Ok(values)
+}
+
+/// Decodes the values of a dictionary page, `None` if `page` is a data page.
+pub(crate) fn decode_dictionary(
+    page: &Page,
+    descr: &ColumnDescriptor,
+) -> ParquetResult<Option<ColumnValues>> {
+    let Page::Dictionary {
+        buffer,
+        num_values,
+        encoding,
+        ..
+    } = page
+    else {
+        return Ok(None);
+    };
+    if !matches!(encoding, Encoding::Plain | Encoding::PlainDictionary) {
+        return Err(ParquetError::NotSupported(format!(
+            "Decoding a {encoding:?} encoded dictionary page"
+        )));
+    }
+    decode_plain(buffer, descr, *num_values as usize).map(Some)
+}
+
+/// Decodes the pages of a column chunk, in order, into a [`ColumnData`].
+pub(crate) struct ColumnDecoder<'a> {
+    descr: &'a ColumnDescriptor,
+    dictionary: Option<ColumnValues>,
+    out: ColumnData,
+}
+
+impl<'a> ColumnDecoder<'a> {
+    pub(crate) fn new(descr: &'a ColumnDescriptor) -> Self {
+        ColumnDecoder {
+            descr,
+            dictionary: None,
+            out: ColumnData::new_empty(descr),
+        }
+    }
+
+    pub(crate) fn finish(self) -> ColumnData {
+        self.out
+    }
+
+    /// Decodes `page`, of which the first row is row `first_row` of the row group, and
+    /// appends the rows in `selection` to the output. Returns the number of rows
+    /// that start in the page.
+    pub(crate) fn push_page(
+        &mut self,
+        page: Page,
+        first_row: usize,
+        selection: Option<&RowSelection>,
+    ) -> ParquetResult<usize> {
+        let page = match self.decode_page(page)? {
+            Some(page) => page,
+            None => return Ok(0),
+        };
+
+        let num_levels = page
+            .rep_levels
+            .as_ref()
+            .or(page.def_levels.as_ref())
+            .map_or(page.values.len(), |levels| levels.len());
+        let num_rows = page.rep_levels.as_ref().map_or(num_levels, |levels| {
+            levels.iter().filter(|l| **l == 0).count()
+        });
+
+        let page = match selection {
+            Some(selection) => self.select_rows(page, first_row, num_rows, selection),
+            None => page,
+        };
+        let selected_rows = match &page.rep_levels {
+            Some(levels) => levels.iter().filter(|l| **l == 0).count(),
+            None => page
+                .def_levels
+                .as_ref()
+                .map_or(page.values.len(), |levels| levels.len()),
+        };
+
+        self.out.values.append(page.values)?;
+        if let (Some(out), Some(levels)) = (&mut self.out.def_levels, page.def_levels) {
+            out.extend(levels);
+        }
+        if let (Some(out), Some(levels)) = (&mut self.out.rep_levels, page.rep_levels) {
+            out.extend(levels);
+        }
+        self.out.num_rows += selected_rows;
+        Ok(num_rows)
+    }
+
+    /// Keeps the levels and values of the rows of the page that are in `selection`.
+    fn select_rows(
+        &self,
+        page: DecodedPage,
+        first_row: usize,
+        num_rows: usize,
+        selection: &RowSelection,
+    ) -> DecodedPage {
+        // A record may continue from the previous page if the file has no offset index,
+        // the levels before the first repetition level of 0 then belong to `first_row - 1`.
+        let mask_start = first_row.saturating_sub(1);
+        let mask = selection.row_mask(mask_start..first_row + num_rows);
+        let mask_offset = first_row - mask_start;
+        if mask[mask_offset..].iter().all(|selected| *selected)
+            && page
+                .rep_levels
+                .as_ref()
+                .is_none_or(|l| l.first() == Some(&0))
+        {
+            return page;
+        }
+
+        let num_levels = page
+            .rep_levels
+            .as_ref()
+            .or(page.def_levels.as_ref())
+            .map_or(page.values.len(), |levels| levels.len());
+        let mut row = mask_offset as isize - 1;
+        let keep_levels = (0..num_levels)
+            .map(|i| {
+                match &page.rep_levels {
+                    Some(levels) if levels[i] != 0 => {}
+                    _ => row += 1,
+                }
+                mask[row.max(0) as usize]
+            })
+            .collect::<Vec<_>>();
+
+        let max_def_level = self.descr.max_def_level();
+        let keep_values = match &page.def_levels {
+            Some(levels) => levels
+                .iter()
+                .zip(&keep_levels)
+                .filter(|(level, _)| **level == max_def_level)
+                .map(|(_, keep)| *keep)
+                .collect(),
+            None => keep_levels.clone(),
+        };
+        let filter_levels = |levels: Option<Vec<i16>>| {
+            levels.map(|levels| {
+                levels
+                    .into_iter()
+                    .zip(&keep_levels)
+                    .filter_map(|(level, keep)| keep.then_some(level))
+                    .collect()
+            })
+        };
+
+        DecodedPage {
+            values: page.values.filter(&keep_values),
+            def_levels: filter_levels(page.def_levels),
+            rep_levels: filter_levels(page.rep_levels),
+        }
+    }
+
+    /// Decodes a data page, or stores the values of a dictionary page and returns `None`.
+    fn decode_page(&mut self, page: Page) -> ParquetResult<Option<DecodedPage>> {
+        let max_def_level = self.descr.max_def_level();
+        let max_rep_level = self.descr.max_rep_level();
+
+        let (def_levels, rep_levels, values, encoding, num_values) = match &page {
+            Page::Dictionary { .. } => {
+                self.dictionary = decode_dictionary(&page, self.descr)?;
+                return Ok(None);
+            }
+            Page::Data {
+                buffer,
+                num_values,
+                encoding,
+                def_level_encoding,
+                rep_level_encoding,
+                ..
+            } => {
+                let num_values = *num_values as usize;
+                let (rep_levels, rest) =
+                    decode_levels_v1(buffer, *rep_level_encoding, max_rep_level, num_values)?;
+                let (def_levels, rest) =
+                    decode_levels_v1(rest, *def_level_encoding, max_def_level, num_values)?;
+                (def_levels, rep_levels, rest, *encoding, num_values)
+            }
+            Page::DataV2 {
+                buffer,
+                num_values,
+                encoding,
+                def_levels_byte_len,
+                rep_levels_byte_len,
+                ..
+            } => {
+                let num_values = *num_values as usize;
+                let rep_len = *rep_levels_byte_len as usize;
+                let def_len = *def_levels_byte_len as usize;
+                if rep_len + def_len > buffer.len() {
+                    return Err(ParquetError::EOF);
+                }
+                let rep_levels = (max_rep_level > 0)
+                    .then(|| decode_levels(&buffer[..rep_len], max_rep_level, num_values))
+                    .transpose()?;
+                let def_levels = (max_def_level > 0)
+                    .then(|| {
+                        decode_levels(
+                            &buffer[rep_len..rep_len + def_len],
+                            max_def_level,
+                            num_values,
+                        )
+                    })
+                    .transpose()?;
+                (
+                    def_levels,
+                    rep_levels,
+                    &buffer[rep_len + def_len..],
+                    *encoding,
+                    num_values,
+                )
+            }
+        };
+
+        let num_non_null = def_levels.as_ref().map_or(num_values, |levels| {
+            levels.iter().filter(|l| **l == max_def_level).count()
+        });
+
+        let values = match encoding {
+            Encoding::Plain => decode_plain(values, self.descr, num_non_null)?,
+            // A page of only nulls may have no indices, and then an empty dictionary.
+            Encoding::PlainDictionary | Encoding::RLE_Dictionary if num_non_null == 0 => {
+                ColumnValues::new_empty(self.descr.physical_type())
+            }
+            Encoding::PlainDictionary | Encoding::RLE_Dictionary => {
+                let dictionary = self.dictionary.as_ref().ok_or_else(|| {
+                    ParquetError::InvalidFormat(
+                        "Dictionary encoded data page without a dictionary page".into(),
+                    )
+                })?;
+                let (bit_width, indices) = values.split_first().ok_or(ParquetError::EOF)?;
+                let indices = decode_hybrid_rle(indices, *bit_width as u32, num_non_null)?;
+                dictionary.gather(&indices)?
+            }
+            Encoding::RLE if self.descr.physical_type() == PhysicalType::Boolean => {
+                let (values, _) = split_length_prefixed(values)?;
+                let values = decode_hybrid_rle(values, 1, num_non_null)?;
+                ColumnValues::Boolean(values.into_iter().map(|v| v == 1).collect())
+            }
+            _ => {
+                return Err(ParquetError::NotSupported(format!(
+                    "Decoding {encoding:?} encoded values"
+                )))
+            }
+        };
+
+        Ok(Some(DecodedPage {
+            values,
+            def_levels,
+            rep_levels,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::types::{ParquetType, SchemaDescriptor, TypeInfo};
+    use crate::metadata::Repetition;
+    use crate::test_util::encode_levels;
+
+    fn field(name: &str, repetition: Option<Repetition>) -> TypeInfo {
+        TypeInfo {
+            name: name.into(),
+            repetition,
+            converted_type: None,
+            logical_type: None,
+            id: None,
+        }
+    }
+
+    #[test]
+    fn test_select_nested_rows() {
+        let schema = SchemaDescriptor::new(ParquetType::Group {
+            info: field("schema", None),
+            fields: vec![ParquetType::Primitive {
+                info: field("a", Some(Repetition::Repeated)),
+                physical: PhysicalType::Int32,
+                type_length: -1,
+                scale: -1,
+                precision: -1,
+            }],
+        });
+        let descr = &schema.leaves[0];
+
+        // [[1, 2], [3], [], [4, 5, 6]]
+        let mut buffer = vec![];
+        encode_levels(&[0, 1, 0, 0, 0, 1, 1], &mut buffer);
+        encode_levels(&[1, 1, 1, 0, 1, 1, 1], &mut buffer);
+        (1..=6i32).for_each(|v| buffer.extend_from_slice(&v.to_le_bytes()));
+        let page = Page::Data {
+            buffer,
+            num_values: 7,
+            encoding: Encoding::Plain,
+            def_level_encoding: Encoding::RLE,
+            rep_level_encoding: Encoding::RLE,
+            statistics: None,
+        };
+
+        let selection = RowSelection::from_deleted_rows([1], 4);
+        let mut decoder = ColumnDecoder::new(descr);
+        assert_eq!(decoder.push_page(page, 0, Some(&selection)).unwrap(), 4);
+
+        let column = decoder.finish();
+        assert_eq!(column.num_rows, 3);
+        assert_eq!(column.rep_levels, Some(vec![0, 1, 0, 0, 1, 1]));
+        assert_eq!(column.def_levels, Some(vec![1, 1, 0, 1, 1, 1]));
+        assert_eq!(column.values, ColumnValues::Int32(vec![1, 2, 4, 5, 6]));
+    }
+
+    #[test]
+    fn test_empty_dictionary() {
+        let schema = SchemaDescriptor::new(ParquetType::Group {
+            info: field("schema", None),
+            fields: vec![ParquetType::Primitive {
+                info: field("a", Some(Repetition::Optional)),
+                physical: PhysicalType::Int32,
+                type_length: -1,
+                scale: -1,
+                precision: -1,
+            }],
+        });
+        let descr = &schema.leaves[0];
+
+        let dictionary = Page::Dictionary {
+            buffer: vec![],
+            num_values: 0,
+            encoding: Encoding::Plain,
+            is_sorted: false,
+        };
+        let mut buffer = vec![];
+        encode_levels(&[0, 0, 0], &mut buffer);
+        let page = Page::Data {
+            buffer,
+            num_values: 3,
+            encoding: Encoding::RLE_Dictionary,
+            def_level_encoding: Encoding::RLE,
+            rep_level_encoding: Encoding::RLE,
+            statistics: None,
+        };
+
+        let mut decoder = ColumnDecoder::new(descr);
+        assert_eq!(decoder.push_page(dictionary, 0, None).unwrap(), 0);
+        assert_eq!(decoder.push_page(page, 0, None).unwrap(), 3);
+
+        let column = decoder.finish();
+        assert_eq!(column.num_rows, 3);
+        assert_eq!(column.def_levels, Some(vec![0, 0, 0]));
+        assert_eq!(column.values, ColumnValues::Int32(vec![]));
+    }
+
+    #[test]
+    fn test_append_different_type() {
+        let mut values = ColumnValues::Int32(vec![1]);
+        assert!(matches!(
+            values.append(ColumnValues::Int64(vec![2])),
+            Err(ParquetError::InvalidFormat(_))
+        ));
+        values.append(ColumnValues::Int32(vec![2])).unwrap();
+        assert_eq!(values, ColumnValues::Int32(vec![1, 2]));
+    }
+}
@@ -0,0 +1,847 @@
+use crate::bloom_filter::read_bloom_filter;
+use crate::data::column::ColumnData;
+use crate::data::row_group::{read_column, read_dictionary};
+use crate::data::selection::RowSelection;
+use crate::errors::{ParquetError, ParquetResult};
+use crate::metadata::{read_page_index, ColumnIndex, OffsetIndex, ParquetMetaData};
+use crate::predicate::Predicate;
+use crate::reader::ParquetReader;
+#[cfg(feature = "rayon")]
+use std::collections::VecDeque;
+#[cfg(any(feature = "rayon", test))]
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+/// The decoded columns of the selected rows of a row group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowGroupBatch {
+    /// Index of the row group in the file.
+    pub row_group: usize,
+    /// Number of selected rows.
+    pub num_rows: usize,
+    /// One entry per projected leaf column, in the order of the projection.
+    pub columns: Vec<ColumnData>,
+}
+
+/// The column index and the offset index of every column of a row group.
+type PageIndexes = (Vec<Option<ColumnIndex>>, Vec<Option<OffsetIndex>>);
+/// Borrowed [`PageIndexes`].
+type PageIndexSlices<'a> = (&'a [Option<ColumnIndex>], &'a [Option<OffsetIndex>]);
+
+/// Reads the row groups of a file, one [`RowGroupBatch`] per row group.
+///
+/// The columns, row groups and rows to read can be restricted with the `with_` methods.
+/// With the `rayon` feature, row groups and their columns can be decoded in parallel, see
+/// [`Self::with_parallelism`].
+pub struct FileReader<R: ParquetReader + Clone> {
+    reader: R,
+    metadata: ParquetMetaData,
+    columns: Vec<usize>,
+    row_groups: Vec<usize>,
+    selection: Option<RowSelection>,
+    predicate: Option<Predicate>,
+    /// Whether the rows that do not match the predicate are dropped.
+    filter_rows: bool,
+    next_row_group: usize,
+    /// The page indexes of every row group, read when first needed, see
+    /// [`Self::page_indexes`].
+    page_indexes: Vec<OnceLock<PageIndexes>>,
+    /// Number of row groups that are decoded at the same time.
+    #[cfg(feature = "rayon")]
+    parallelism: usize,
+    #[cfg(feature = "rayon")]
+    thread_pool: Option<Arc<rayon::ThreadPool>>,
+    /// Batches that were decoded in parallel, but not yet returned.
+    #[cfg(feature = "rayon")]
+    decoded: VecDeque<ParquetResult<RowGroupBatch>>,
+}
+
+impl<R: ParquetReader + Clone> FileReader<R> {
+    /// Creates a reader of all rows of all leaf columns.
+    pub fn new(reader: R, metadata: ParquetMetaData) -> Self {
+        let columns = (0..metadata.file_metadata.schema_descr.num_columns()).collect();
+        let row_groups = (0..metadata.row_groups.len()).collect();
+        let page_indexes = metadata
+            .row_groups
+            .iter()
+            .map(|_| OnceLock::new())
+            .collect();
+        FileReader {
+            reader,
+            metadata,
+            columns,
+            row_groups,
+            selection: None,
+            predicate: None,
+            filter_rows: false,
+            next_row_group: 0,
+            page_indexes,
+            #[cfg(feature = "rayon")]
+            parallelism: 1,
+            #[cfg(feature = "rayon")]
+            thread_pool: None,
+            #[cfg(feature = "rayon")]
+            decoded: VecDeque::new(),
+        }
+    }
+
+    /// Only reads the leaf columns at `columns`.
+    pub fn with_columns(mut self, columns: Vec<usize>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Only reads the row groups at `row_groups`, in that order.
+    pub fn with_row_groups(mut self, row_groups: Vec<usize>) -> Self {
+        self.row_groups = row_groups;
+        self
+    }
+
+    /// Only reads the rows in `selection`. The selection covers the rows of the row
+    /// groups that are read, one after another.
+    ///
+    /// Pages without selected rows are not read. Unless the metadata has the page indexes,
+    /// the offset indexes of a row group are read once, when it is first read.
+    pub fn with_row_selection(mut self, selection: RowSelection) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    /// Skips the row groups of which the statistics show that no row matches `predicate`.
+    /// For equality and `IN`, the bloom filters and the dictionaries of fully dictionary
+    /// encoded column chunks are read when the statistics are not enough.
+    ///
+    /// Within the other row groups, the rows of the pages of which the column indexes show
+    /// that no row matches are skipped, and those pages are not read. The remaining rows
+    /// are not filtered.
+    pub fn with_predicate(mut self, predicate: Predicate) -> Self {
+        self.predicate = Some(predicate);
+        self
+    }
+
+    /// Only reads the rows that match `predicate`, and skips row groups like
+    /// [`Self::with_predicate`].
+    ///
+    /// Every row group is read in two phases: the columns of the predicate are decoded
+    /// first, then the projected columns are only decoded for the matching rows. With the
+    /// offset indexes, the pages without matching rows are not read. The columns of the
+    /// predicate must not be nested in a list, and the columns that are not in the file are
+    /// null in every row.
+    pub fn with_row_filter(mut self, predicate: Predicate) -> Self {
+        self.predicate = Some(predicate);
+        self.filter_rows = true;
+        self
+    }
+
+    /// Decodes up to `parallelism` row groups at the same time, and the columns of every
+    /// row group in parallel. The batches are still returned in the order of the row groups.
+    ///
+    /// The work runs on the global rayon thread pool, or on the pool given to
+    /// [`Self::with_thread_pool`]. `parallelism` also bounds the number of decoded batches
+    /// that are held in memory. With a `parallelism` of 1, the default, the reader decodes
+    /// on the calling thread.
+    #[cfg(feature = "rayon")]
+    pub fn with_parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism.max(1);
+        self
+    }
+
+    /// Decodes in parallel on `thread_pool` instead of the global rayon thread pool. The
+    /// size of the pool caps the number of threads that are used.
+    #[cfg(feature = "rayon")]
+    pub fn with_thread_pool(mut self, thread_pool: Arc<rayon::ThreadPool>) -> Self {
+        self.thread_pool = Some(thread_pool);
+        self
+    }
+
+    pub fn metadata(&self) -> &ParquetMetaData {
+        &self.metadata
+    }
+
+    /// Returns the column indexes and the offset indexes of the columns of `row_group`.
+    ///
+    /// Without them in the metadata, they are read the first time they are needed, for the
+    /// projected columns and the columns of the predicate, and kept for the next reads of
+    /// the row group.
+    fn page_indexes(&self, row_group: usize) -> ParquetResult<PageIndexSlices<'_>> {
+        let no_row_group =
+            || ParquetError::InvalidFormat(format!("The file has no row group {row_group}"));
+        if let (Some(column_indexes), Some(offset_indexes)) =
+            (&self.metadata.page_indexes, &self.metadata.offset_indexes)
+        {
+            return column_indexes
+                .get(row_group)
+                .zip(offset_indexes.get(row_group))
+                .map(|(column_indexes, offset_indexes)| {
+                    (column_indexes.as_slice(), offset_indexes.as_slice())
+                })
+                .ok_or_else(no_row_group);
+        }
+
+        let cached = self.page_indexes.get(row_group).ok_or_else(no_row_group)?;
+        let (column_indexes, offset_indexes) = match cached.get() {
+            Some(indexes) => indexes,
+            None => {
+                let mut columns = self.columns.clone();
+                if let Some(predicate) = &self.predicate {
+                    columns.extend(predicate.columns_in(&self.metadata.row_groups[row_group]));
+                }
+                columns.sort_unstable();
+                columns.dedup();
+                let (mut column_indexes, mut offset_indexes) =
+                    read_page_index(self.reader.clone(), &self.metadata, &[row_group], &columns)?;
+                // Another thread may have read them meanwhile, they are the same.
+                cached.get_or_init(|| {
+                    (
+                        column_indexes.swap_remove(row_group),
+                        offset_indexes.swap_remove(row_group),
+                    )
+                })
+            }
+        };
+        Ok((column_indexes, offset_indexes))
+    }
+
+    /// Returns the offset index of every column in `columns` of row group `row_group`.
+    fn offset_indexes(
+        &self,
+        row_group: usize,
+        columns: &[usize],
+    ) -> ParquetResult<Vec<Option<&OffsetIndex>>> {
+        let (_, offset_indexes) = self.page_indexes(row_group)?;
+        Ok(columns
+            .iter()
+            .map(|column| offset_indexes.get(*column).and_then(Option::as_ref))
+            .collect())
+    }
+
+    /// Whether rows of `row_group` may match the predicate, if any.
+    ///
+    /// Bloom filters and dictionaries that cannot be read are not used, the row group is
+    /// then only skipped if the rest shows that no row matches.
+    fn can_match(&self, row_group: usize) -> bool {
+        let Some(predicate) = &self.predicate else {
+            return true;
+        };
+        let metadata = &self.metadata.row_groups[row_group];
+        if !predicate.can_match(metadata) {
+            return false;
+        }
+
+        let columns = predicate.equality_columns(metadata);
+        let mut bloom_filters = vec![None; metadata.columns.len()];
+        for i in &columns {
+            bloom_filters[*i] = read_bloom_filter(self.reader.clone(), &metadata.columns[*i])
+                .ok()
+                .flatten();
+        }
+        if bloom_filters.iter().any(Option::is_some)
+            && !predicate
+                .evaluate_with_bloom_filters(metadata, &bloom_filters)
+                .may_be_true
+        {
+            return false;
+        }
+
+        // Reading a dictionary is more expensive than a bloom filter, but it is exact.
+        let mut dictionaries = vec![None; metadata.columns.len()];
+        for i in columns {
+            if metadata.columns[i].is_dictionary_encoded() {
+                dictionaries[i] = read_dictionary(self.reader.clone(), metadata, i)
+                    .ok()
+                    .flatten();
+            }
+        }
+        if dictionaries.iter().all(Option::is_none) {
+            return true;
+        }
+        predicate
+            .evaluate_with_column_filters(metadata, &bloom_filters, &dictionaries)
+            .may_be_true
+    }
+
+    /// Skips the rows of the pages of `row_group` of which the column indexes show that no
+    /// row matches the predicate, if any. `None` selects every row.
+    fn prune_pages(
+        &self,
+        row_group: usize,
+        selection: Option<RowSelection>,
+    ) -> ParquetResult<Option<RowSelection>> {
+        let Some(predicate) = &self.predicate else {
+            return Ok(selection);
+        };
+        let metadata = &self.metadata.row_groups[row_group];
+        let columns = predicate.columns_in(metadata);
+        if columns.is_empty() {
+            return Ok(selection);
+        }
+        let (column_indexes, offset_indexes) = self.page_indexes(row_group)?;
+        let pages = predicate.evaluate_pages(metadata, column_indexes, offset_indexes)?;
+        if pages.selected_row_count() == metadata.num_rows as usize {
+            return Ok(selection);
+        }
+        Ok(Some(match selection {
+            Some(selection) => selection.intersection(&pages),
+            None => pages,
+        }))
+    }
+
+    /// Decodes the columns of the predicate and returns the rows of `selection`, or of the
+    /// row group, that match it.
+    fn matching_rows(
+        &self,
+        row_group: usize,
+        selection: Option<&RowSelection>,
+    ) -> ParquetResult<RowSelection> {
+        let predicate = self.predicate.as_ref().expect("a row filter is set");
+        let metadata = &self.metadata.row_groups[row_group];
+        let columns = predicate.columns_in(metadata);
+        let offset_indexes = match selection {
+            Some(_) => self.offset_indexes(row_group, &columns)?,
+            None => vec![None; columns.len()],
+        };
+
+        let mut data = vec![None; metadata.columns.len()];
+        for (column, offset_index) in columns.iter().zip(&offset_indexes) {
+            data[*column] = Some(read_column(
+                self.reader.clone(),
+                metadata,
+                *column,
+                *offset_index,
+                selection,
+            )?);
+        }
+        let num_rows = selection.map_or(metadata.num_rows as usize, |selection| {
+            selection.selected_row_count()
+        });
+        let matches =
+            RowSelection::from_filter(&predicate.evaluate_rows(metadata, &data, num_rows)?);
+        Ok(match selection {
+            Some(selection) => selection.and_then(&matches),
+            None => matches,
+        })
+    }
+
+    fn read_row_group(
+        &self,
+        row_group: usize,
+        selection: Option<&RowSelection>,
+    ) -> ParquetResult<RowGroupBatch> {
+        let metadata = &self.metadata.row_groups[row_group];
+        let offset_indexes = match selection {
+            Some(_) => self.offset_indexes(row_group, &self.columns)?,
+            None => vec![None; self.columns.len()],
+        };
+
+        let read = |(column, offset_index): (&usize, &Option<&OffsetIndex>)| {
+            read_column(
+                self.reader.clone(),
+                metadata,
+                *column,
+                *offset_index,
+                selection,
+            )
+        };
+        #[cfg(feature = "rayon")]
+        let columns = if self.parallelism > 1 {
+            use rayon::prelude::*;
+            self.columns
+                .par_iter()
+                .zip(&offset_indexes)
+                .map(read)
+                .collect::<ParquetResult<Vec<_>>>()?
+        } else {
+            self.columns
+                .iter()
+                .zip(&offset_indexes)
+                .map(read)
+                .collect::<ParquetResult<Vec<_>>>()?
+        };
+        #[cfg(not(feature = "rayon"))]
+        let columns = self
+            .columns
+            .iter()
+            .zip(&offset_indexes)
+            .map(read)
+            .collect::<ParquetResult<Vec<_>>>()?;
+
+        let num_rows = selection.map_or(metadata.num_rows as usize, |selection| {
+            selection.selected_row_count()
+        });
+        Ok(RowGroupBatch {
+            row_group,
+            num_rows,
+            columns,
+        })
+    }
+
+    /// Returns the next row group to read with the rows of the selection in it, skipping
+    /// the row groups without selected rows.
+    fn next_row_group(&mut self) -> Option<(usize, Option<RowSelection>)> {
+        while let Some(&row_group) = self.row_groups.get(self.next_row_group) {
+            self.next_row_group += 1;
+            let num_rows = self.metadata.row_groups[row_group].num_rows as usize;
+            let selection = self
+                .selection
+                .as_mut()
+                .map(|selection| selection.split_off(num_rows));
+            if selection
+                .as_ref()
+                .is_some_and(|selection| selection.selected_row_count() == 0)
+            {
+                continue;
+            }
+            return Some((row_group, selection));
+        }
+        None
+    }
+
+    /// Reads a row group, `None` if no row of it matches the predicate.
+    fn decode_row_group(
+        &self,
+        row_group: usize,
+        selection: Option<RowSelection>,
+    ) -> ParquetResult<Option<RowGroupBatch>> {
+        if !self.can_match(row_group) {
+            return Ok(None);
+        }
+        let selection = self.prune_pages(row_group, selection)?;
+        if selection
+            .as_ref()
+            .is_some_and(|selection| selection.selected_row_count() == 0)
+        {
+            return Ok(None);
+        }
+        let selection = if self.filter_rows {
+            let selection = self.matching_rows(row_group, selection.as_ref())?;
+            if selection.selected_row_count() == 0 {
+                return Ok(None);
+            }
+            Some(selection)
+        } else {
+            selection
+        };
+        self.read_row_group(row_group, selection.as_ref()).map(Some)
+    }
+
+    /// Decodes the next `parallelism` row groups in parallel into `decoded`.
+    #[cfg(feature = "rayon")]
+    fn decode_in_parallel(&mut self) {
+        use rayon::prelude::*;
+        let parallelism = self.parallelism;
+        let row_groups = std::iter::from_fn(|| self.next_row_group())
+            .take(parallelism)
+            .collect::<Vec<_>>();
+        let decode = || {
+            row_groups
+                .into_par_iter()
+                .map(|(row_group, selection)| self.decode_row_group(row_group, selection))
+                .collect::<Vec<_>>()
+        };
+        let batches = match &self.thread_pool {
+            Some(thread_pool) => thread_pool.install(decode),
+            None => decode(),
+        };
+        self.decoded
+            .extend(batches.into_iter().filter_map(Result::transpose));
+    }
+}
+
+impl<R: ParquetReader + Clone> Iterator for FileReader<R> {
+    type Item = ParquetResult<RowGroupBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        #[cfg(feature = "rayon")]
+        if self.parallelism > 1 {
+            while self.decoded.is_empty() && self.next_row_group < self.row_groups.len() {
+                self.decode_in_parallel();
+            }
+            return self.decoded.pop_front();
+        }
+        while let Some((row_group, selection)) = self.next_row_group() {
+            if let Some(batch) = self.decode_row_group(row_group, selection).transpose() {
+                return Some(batch);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::column::ColumnValues;
+    use crate::metadata::types::ColumnPath;
+    use crate::metadata::{get_metadata, Encoding, PageType, Statistics};
+    use crate::physical::ByteArray;
+    use crate::predicate::CompareOp;
+    use crate::test_util::{
+        write_file, write_file_with_column_orders, Float16, TestColumn, TestValues,
+    };
+    use std::ops::Range;
+    use std::sync::Mutex;
+
+    /// Reads from a byte slice and records the ranges that were read.
+    #[derive(Clone)]
+    struct RecordingReader<'a> {
+        bytes: &'a [u8],
+        reads: Arc<Mutex<Vec<Range<usize>>>>,
+    }
+
+    impl<'a> ParquetReader for RecordingReader<'a> {
+        type Reader = &'a [u8];
+
+        fn get_reader(&self, start: usize, length: usize) -> ParquetResult<Self::Reader> {
+            self.reads.lock().unwrap().push(start..start + length);
+            self.bytes.get_reader(start, length)
+        }
+
+        fn len(&self) -> usize {
+            self.bytes.len()
+        }
+
+        fn get_bytes(&self, start: usize, length: usize) -> ParquetResult<&[u8]> {
+            self.reads.lock().unwrap().push(start..start + length);
+            self.bytes.get_bytes(start, length)
+        }
+    }
+
+    #[test]
+    fn test_read_selected_rows() {
+        let pages = (0..4)
+            .map(|page| {
+                (0..10)
+                    .map(|i| Some(page * 10 + i).filter(|v| v % 3 != 0))
+                    .collect()
+            })
+            .collect();
+        let file = write_file(vec![TestColumn::new("a", true, TestValues::Int32(pages))]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+
+        // rows 8..12 and 35..40, except the deleted row 36
+        let selection = RowSelection::from_ranges([8..12, 35..40], 40)
+            .intersection(&RowSelection::from_deleted_rows([36], 40));
+        let batches = FileReader::new(file.as_slice(), metadata)
+            .with_row_selection(selection)
+            .collect::<ParquetResult<Vec<_>>>()
+            .unwrap();
+
+        let column = &batches[0].columns[0];
+        assert_eq!(batches[0].num_rows, 8);
+        assert_eq!(column.num_rows, 8);
+        assert_eq!(
+            column.def_levels.as_deref(),
+            Some([1, 0, 1, 1, 1, 1, 1, 0].as_slice())
+        );
+        assert_eq!(
+            column.values,
+            ColumnValues::Int32(vec![8, 10, 11, 35, 37, 38])
+        );
+    }
+
+    #[test]
+    fn test_skip_pages_with_column_index() {
+        let pages = (0..4)
+            .map(|page| (0..10).map(|i| Some(page * 10 + i)).collect())
+            .collect();
+        let file = write_file(vec![TestColumn::new("a", false, TestValues::Int32(pages))]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let (_, offset_indexes) = read_page_index(file.as_slice(), &metadata, &[0], &[0]).unwrap();
+        let locations = offset_indexes[0][0]
+            .as_ref()
+            .unwrap()
+            .page_locations
+            .clone();
+
+        let reader = RecordingReader {
+            bytes: file.as_slice(),
+            reads: Default::default(),
+        };
+        let a_gt_15 = Predicate::compare(ColumnPath::new(vec!["a".into()]), CompareOp::Gt, 15);
+        let batches = FileReader::new(reader.clone(), metadata)
+            .with_predicate(a_gt_15)
+            .collect::<ParquetResult<Vec<_>>>()
+            .unwrap();
+
+        // The first page only holds 0..10, the rows of the others are not filtered.
+        assert_eq!(batches[0].num_rows, 30);
+        assert_eq!(
+            batches[0].columns[0].values,
+            ColumnValues::Int32((10..40).collect())
+        );
+        let first_page =
+            locations[0].offset..locations[0].offset + locations[0].compressed_page_size;
+        let reads = reader.reads.lock().unwrap();
+        assert!(reads.iter().any(|read| read.start == locations[1].offset));
+        assert!(reads
+            .iter()
+            .all(|read| read.end <= first_page.start || read.start >= first_page.end));
+    }
+
+    #[test]
+    fn test_keep_pages_without_column_orders() {
+        let pages = (0..4)
+            .map(|page| (0..10).map(|i| Some(page * 10 + i)).collect())
+            .collect();
+        let column = TestColumn::new("a", false, TestValues::Int32(pages));
+        let file = write_file_with_column_orders(vec![column], false);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let (column_indexes, _) = read_page_index(file.as_slice(), &metadata, &[0], &[0]).unwrap();
+        let Some(Statistics::Int32(stats)) = column_indexes[0][0]
+            .as_ref()
+            .map(|index| index.page_statistics(0))
+        else {
+            panic!("expected the column index of an INT32 column");
+        };
+        assert_eq!((stats.min(), stats.max()), (None, None));
+
+        // Without a column order, the order of the page bounds is unknown.
+        let a_gt_15 = Predicate::compare(ColumnPath::new(vec!["a".into()]), CompareOp::Gt, 15);
+        let batches = FileReader::new(file.as_slice(), metadata)
+            .with_predicate(a_gt_15)
+            .collect::<ParquetResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(batches[0].num_rows, 40);
+    }
+
+    #[test]
+    fn test_read_page_index_once() {
+        let pages = (0..4)
+            .map(|page| (0..10).map(|i| Some(page * 10 + i)).collect())
+            .collect();
+        let file = write_file(vec![TestColumn::new("a", false, TestValues::Int32(pages))]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let offset_index = metadata.row_groups[0].columns[0].offset_index_offset();
+
+        let reader = RecordingReader {
+            bytes: file.as_slice(),
+            reads: Default::default(),
+        };
+        let batches = FileReader::new(reader.clone(), metadata)
+            .with_row_groups(vec![0; 3])
+            .with_row_selection(RowSelection::from_ranges([5..10, 45..50, 85..90], 120))
+            .collect::<ParquetResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(batches.len(), 3);
+        let reads = reader.reads.lock().unwrap();
+        let index_reads = reads
+            .iter()
+            .filter(|read| Some(read.start) == offset_index)
+            .count();
+        assert_eq!(index_reads, 1);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_read_in_parallel() {
+        let pages = (0..4)
+            .map(|page| (0..10).map(|i| Some(page * 10 + i)).collect())
+            .collect();
+        let file = write_file(vec![
+            TestColumn::new("a", false, TestValues::Int32(pages)),
+            TestColumn::new("b", false, TestValues::Int64(vec![vec![Some(1); 40]])),
+        ]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+
+        // The same row group five times, with other rows selected every time.
+        let ranges = (0..5).map(|i| i * 40 + i * 3..i * 40 + i * 3 + 2);
+        let read = |parallelism| {
+            FileReader::new(file.as_slice(), metadata.clone())
+                .with_row_groups(vec![0; 5])
+                .with_row_selection(RowSelection::from_ranges(ranges.clone(), 200))
+                .with_parallelism(parallelism)
+                .with_thread_pool(Arc::new(
+                    rayon::ThreadPoolBuilder::new()
+                        .num_threads(2)
+                        .build()
+                        .unwrap(),
+                ))
+                .collect::<ParquetResult<Vec<_>>>()
+                .unwrap()
+        };
+        let batches = read(3);
+        assert_eq!(batches, read(1));
+        let values = batches
+            .iter()
+            .map(|batch| batch.columns[0].values.clone())
+            .collect::<Vec<_>>();
+        let expected = (0..5)
+            .map(|i| ColumnValues::Int32(vec![i * 3, i * 3 + 1]))
+            .collect::<Vec<_>>();
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn test_filter_rows() {
+        let a = (0..4)
+            .map(|page| {
+                (0..10)
+                    .map(|i| Some(page * 10 + i).filter(|v| v % 7 != 0))
+                    .collect()
+            })
+            .collect();
+        let b = vec![(0..40)
+            .map(|i| Some(format!("b{i}").into_bytes()))
+            .collect()];
+        let file = write_file(vec![
+            TestColumn::new("a", true, TestValues::Int32(a)),
+            TestColumn::new("b", false, TestValues::ByteArray(b)),
+        ]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+
+        // (a >= 12 AND a < 16) OR a IN (33, 35), where 14 and 35 are null
+        let a = || ColumnPath::new(vec!["a".into()]);
+        let filter = Predicate::compare(a(), CompareOp::GtEq, 12)
+            .and(Predicate::compare(a(), CompareOp::Lt, 16))
+            .or(Predicate::In {
+                column: a(),
+                values: vec![33.into(), 35.into()],
+            });
+        let batches = FileReader::new(file.as_slice(), metadata)
+            .with_columns(vec![1])
+            .with_row_filter(filter)
+            .collect::<ParquetResult<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(batches[0].num_rows, 4);
+        let b = ["b12", "b13", "b15", "b33"]
+            .iter()
+            .map(|b| ByteArray::new(b.as_bytes().to_vec()))
+            .collect();
+        assert_eq!(batches[0].columns[0].values, ColumnValues::ByteArray(b));
+    }
+
+    #[test]
+    fn test_filter_missing_column() {
+        let a = vec![(0..10).map(Some).collect()];
+        let file = write_file(vec![TestColumn::new("a", false, TestValues::Int32(a))]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+
+        let a = || ColumnPath::new(vec!["a".into()]);
+        let missing = || ColumnPath::new(vec!["missing".into()]);
+        let num_rows = |filter: Predicate| {
+            let batches = FileReader::new(file.as_slice(), metadata.clone())
+                .with_row_filter(filter)
+                .collect::<ParquetResult<Vec<_>>>()
+                .unwrap();
+            batches.iter().map(|batch| batch.num_rows).sum::<usize>()
+        };
+        assert_eq!(num_rows(Predicate::compare(missing(), CompareOp::Eq, 1)), 0);
+        assert_eq!(
+            num_rows(Predicate::compare(missing(), CompareOp::Eq, 1).not()),
+            0
+        );
+        let is_null = Predicate::IsNull(missing());
+        assert_eq!(num_rows(is_null.clone()), 10);
+        let filter = Predicate::compare(a(), CompareOp::GtEq, 4).and(is_null);
+        assert_eq!(num_rows(filter), 6);
+
+        // The row group is not skipped, and its rows are not filtered.
+        let batches = FileReader::new(file.as_slice(), metadata.clone())
+            .with_predicate(Predicate::compare(missing(), CompareOp::Eq, 1))
+            .collect::<ParquetResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(batches[0].num_rows, 10);
+    }
+
+    #[test]
+    fn test_filter_float16_rows() {
+        // 1.5, -2.0, 0.5 and null
+        let values = [Some(0x3E00), Some(0xC000), Some(0x3800), None];
+        let pages = vec![values.iter().map(|v| v.map(Float16)).collect()];
+        let file = write_file(vec![TestColumn::new("a", true, TestValues::Float16(pages))]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let Some(Statistics::Float(stats)) = metadata.row_groups[0].columns[0].statistics() else {
+            panic!("expected FLOAT16 statistics decoded to f32");
+        };
+        assert_eq!((stats.min(), stats.max()), (Some(&-2.0), Some(&1.5)));
+
+        let a = || ColumnPath::new(vec!["a".into()]);
+        let skipped = FileReader::new(file.as_slice(), metadata.clone())
+            .with_predicate(Predicate::compare(a(), CompareOp::Gt, 1.5f32))
+            .count();
+        assert_eq!(skipped, 0);
+
+        let batches = FileReader::new(file.as_slice(), metadata)
+            .with_row_filter(Predicate::compare(a(), CompareOp::Gt, 0.0f32))
+            .collect::<ParquetResult<Vec<_>>>()
+            .unwrap();
+        let values = batches[0].columns[0].values.decode_float16().unwrap();
+        assert_eq!(values, vec![1.5, 0.5]);
+    }
+
+    #[test]
+    fn test_skip_row_group_with_bloom_filter() {
+        let ids = vec![(0..100i64).map(|i| Some(i * 2)).collect()];
+        let mut column = TestColumn::new("id", false, TestValues::Int64(ids));
+        column.bloom_filter = true;
+        let file = write_file(vec![column]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+
+        let num_batches = |id: i64| {
+            let id_eq = Predicate::compare(ColumnPath::new(vec!["id".into()]), CompareOp::Eq, id);
+            assert!(id_eq.can_match(&metadata.row_groups[0]));
+            FileReader::new(file.as_slice(), metadata.clone())
+                .with_predicate(id_eq)
+                .count()
+        };
+        assert_eq!(num_batches(50), 1);
+        // Between min and max, but not in the bloom filter
+        assert_eq!(num_batches(51), 0);
+
+        // A negative `num_bytes` in the header of the bloom filter.
+        let offset = metadata.row_groups[0].columns[0]
+            .bloom_filter_offset()
+            .unwrap();
+        let mut file = file.clone();
+        assert_eq!(file[offset..offset + 3], [0x15, 0x80, 0x02]);
+        file[offset + 1] = 0x81;
+        let batches = FileReader::new(file.as_slice(), metadata.clone())
+            .with_predicate(Predicate::compare(
+                ColumnPath::new(vec!["id".into()]),
+                CompareOp::Eq,
+                51i64,
+            ))
+            .collect::<ParquetResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(batches.len(), 1);
+    }
+
+    #[test]
+    fn test_skip_row_group_with_dictionary() {
+        let cities = ["amsterdam", "delft", "zwolle"];
+        let pages = (0..2)
+            .map(|_| cities.iter().map(|c| Some(c.as_bytes().to_vec())).collect())
+            .collect();
+        let mut column = TestColumn::new("city", true, TestValues::ByteArray(pages));
+        column.dictionary = true;
+        let file = write_file(vec![column]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let chunk = &metadata.row_groups[0].columns[0];
+        assert!(chunk.is_dictionary_encoded());
+        assert_eq!(
+            chunk.page_count(PageType::DataPageV1, Encoding::RLE_Dictionary),
+            Some(2)
+        );
+        assert_eq!(
+            chunk.page_count(PageType::DictionaryPage, Encoding::Plain),
+            Some(1)
+        );
+
+        let city_in = |values: &[&str]| Predicate::In {
+            column: ColumnPath::new(vec!["city".into()]),
+            values: values.iter().map(|v| (*v).into()).collect(),
+        };
+        let read = |predicate| {
+            FileReader::new(file.as_slice(), metadata.clone())
+                .with_predicate(predicate)
+                .collect::<ParquetResult<Vec<_>>>()
+                .unwrap()
+        };
+        let batches = read(city_in(&["delft", "utrecht"]));
+        assert_eq!(batches[0].columns[0].values.len(), 6);
+        // Between min and max, but not in the dictionary
+        assert!(read(city_in(&["rotterdam", "utrecht"])).is_empty());
+    }
+}
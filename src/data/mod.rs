@@ -1,6 +1,13 @@
 use crate::metadata::ParquetMetaData;
 
+mod column;
+mod file;
 mod page;
 mod row_group;
+mod selection;
 
+pub use column::{ColumnData, ColumnValues};
+pub use file::{FileReader, RowGroupBatch};
+pub use page::Page;
 pub use row_group::*;
+pub use selection::*;
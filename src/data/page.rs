@@ -1,9 +1,8 @@
 use crate::compression::Decompressor;
 use crate::errors::{ParquetError, ParquetResult};
-use crate::metadata::{Encoding, PageType, PhysicalType, Statistics, TPageHeader};
+use crate::metadata::types::ColumnDescriptor;
+use crate::metadata::{Encoding, LogicalType, PageType, Statistics, TPageHeader};
 use std::io::Read;
-use std::os::linux::raw::stat;
-use std::sync::Arc;
 use thrift::protocol::{TCompactInputProtocol, TSerializable};
 
 pub enum Page {
@@ -25,7 +24,7 @@ pub enum Page {
         def_levels_byte_len: u32,
         rep_levels_byte_len: u32,
         is_compressed: bool,
-        statstics: Option<Statistics>,
+        statistics: Option<Statistics>,
     },
     Dictionary {
         buffer: Vec<u8>,
@@ -36,6 +35,10 @@ pub enum Page {
 }
 
 impl Page {
+    pub fn is_dictionary(&self) -> bool {
+        matches!(self, Page::Dictionary { .. })
+    }
+
     pub fn buffer(&self) -> &[u8] {
         match self {
             Page::Data { buffer, .. } => buffer,
@@ -98,9 +101,15 @@ pub fn read_page_header<R: Read>(input: R) -> ParquetResult<(usize, TPageHeader)
 pub(crate) fn decode_page(
     header: TPageHeader,
     input: Vec<u8>,
-    physical_type: PhysicalType,
+    column_descr: &ColumnDescriptor,
     decompressor: Option<&mut Box<dyn Decompressor>>,
 ) -> ParquetResult<Page> {
+    // Like the statistics of column chunks, FLOAT16 bounds are decoded to `f32`.
+    let decode_statistics = |statistics| match column_descr.logical_type() {
+        Some(LogicalType::Float16) => Statistics::from_thrift_float16(statistics).map(Some),
+        _ => Statistics::from_thrift(column_descr.physical_type(), statistics),
+    };
+
     // When processing data page v2, depending on enabled compression for the
     // page, we should account for uncompressed data ('offset') of
     // repetition and definition levels.
@@ -134,7 +143,7 @@ pub(crate) fn decode_page(
 
             if out.len() != uncompressed_size {
                 return Err(ParquetError::InvalidFormat(format!(
-                    "Actual decompressed size: {uncompressed_size} doesn't match the expected: {}",
+                    "Actual decompressed size: {} doesn't match the expected: {uncompressed_size}",
                     out.len()
                 )));
             }
@@ -145,32 +154,65 @@ pub(crate) fn decode_page(
 
     let page = match header.type_.into() {
         PageType::DictionaryPage => {
-            let dict_header = header.dictionary_page_header.unwrap();
+            let dict_header = header.dictionary_page_header.ok_or_else(|| {
+                ParquetError::InvalidFormat("Missing dictionary page header".into())
+            })?;
             let is_sorted = dict_header.is_sorted.unwrap_or(false);
             Page::Dictionary {
                 buffer,
                 num_values: dict_header.num_values as u32,
-                encoding: dict_header.encoding.try_into().unwrap(),
+                encoding: dict_header.encoding.into(),
                 is_sorted,
             }
         }
         PageType::DataPageV1 => {
-            let data_header = header.data_page_header.unwrap();
+            let data_header = header
+                .data_page_header
+                .ok_or_else(|| ParquetError::InvalidFormat("Missing data page header".into()))?;
             Page::Data {
                 buffer,
                 num_values: data_header.num_values as u32,
-                encoding: data_header.encoding.try_into().unwrap(),
-                def_level_encoding: data_header.definition_level_encoding.try_into().unwrap(),
-                rep_level_encoding: data_header.repetition_level_encoding.try_into().unwrap(),
+                encoding: data_header.encoding.into(),
+                def_level_encoding: data_header.definition_level_encoding.into(),
+                rep_level_encoding: data_header.repetition_level_encoding.into(),
                 statistics: data_header
                     .statistics
-                    .map(|stats| Statistics::from_thrift(physical_type, stats))
+                    .map(decode_statistics)
                     .transpose()?
                     .flatten(),
             }
         }
-        _ => todo!(),
+        PageType::DataPageV2 => {
+            let data_header = header
+                .data_page_header_v2
+                .ok_or_else(|| ParquetError::InvalidFormat("Missing data page v2 header".into()))?;
+            Page::DataV2 {
+                buffer,
+                num_values: data_header.num_values as u32,
+                encoding: data_header.encoding.into(),
+                num_nulls: data_header.num_nulls as u32,
+                num_rows: data_header.num_rows as u32,
+                def_levels_byte_len: data_header.definition_levels_byte_length as u32,
+                rep_levels_byte_len: data_header.repetition_levels_byte_length as u32,
+                is_compressed: data_header.is_compressed.unwrap_or(true),
+                statistics: data_header
+                    .statistics
+                    .map(decode_statistics)
+                    .transpose()?
+                    .flatten(),
+            }
+        }
+        PageType::IndexPage => {
+            return Err(ParquetError::NotSupported(
+                "Index pages cannot be decoded".into(),
+            ))
+        }
+        PageType::Unknown(page_type) => {
+            return Err(ParquetError::NotSupported(format!(
+                "Decoding pages of unknown type {page_type}"
+            )))
+        }
     };
 
-    todo!()
+    Ok(page)
 }
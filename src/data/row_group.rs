@@ -1,16 +1,19 @@
 use crate::compression::{create_decompressor, Decompressor};
-use crate::data::page::{read_page_header, Page, PageMetadata, PageReader};
+use crate::data::column::{decode_dictionary, ColumnData, ColumnDecoder, ColumnValues};
+use crate::data::page::{decode_page, read_page_header, Page, PageMetadata, PageReader};
+use crate::data::selection::RowSelection;
 use crate::errors::{ParquetError, ParquetResult};
+use crate::metadata::types::ColumnDescriptorPtr;
 use crate::metadata::{
-    ColumnChunkMetaData, PageType, PhysicalType, RowGroupMetaData, TPageHeader, TPageLocation,
+    ColumnChunkMetaData, OffsetIndex, PageLocation, PageType, RowGroupMetaData, TPageHeader,
 };
 use crate::reader::ParquetReader;
 use std::collections::VecDeque;
 
-struct SerPageReader<R: ParquetReader> {
+pub(crate) struct SerPageReader<R: ParquetReader> {
     reader: R,
     decompressor: Option<Box<dyn Decompressor>>,
-    physical_type: PhysicalType,
+    column_descr: ColumnDescriptorPtr,
     state: PageReaderState,
 }
 
@@ -21,33 +24,85 @@ enum PageReaderState {
         // length of the chunk in bytes
         remaining_bytes: usize,
         // if next page header has been 'peeked' it is cached her
-        next_page_header: Option<TPageHeader>,
+        next_page_header: Option<Box<TPageHeader>>,
     },
     Pages {
         /// remaining page locations
-        page_locations: VecDeque<TPageLocation>,
+        page_locations: VecDeque<PageLocation>,
         /// reaming dictionary locations if any
-        dictionary_page: Option<TPageLocation>,
+        dictionary_page: Option<PageLocation>,
         total_rows: usize,
     },
 }
 
 impl<R: ParquetReader> SerPageReader<R> {
-    fn new(reader: R, metadata: &ColumnChunkMetaData, total_rows: usize) -> Self {
+    /// Creates a page reader that reads the pages of the column chunk one after another.
+    pub(crate) fn new(reader: R, metadata: &ColumnChunkMetaData) -> ParquetResult<Self> {
         let (offset, remaining_bytes) = metadata.byte_range();
         let state = PageReaderState::Values {
             offset,
             remaining_bytes,
             next_page_header: None,
         };
+        Self::with_state(reader, metadata, state)
+    }
+
+    /// Creates a page reader that uses the page locations of the [`OffsetIndex`], so that
+    /// pages can be peeked at and skipped without reading them.
+    pub(crate) fn new_with_offset_index(
+        reader: R,
+        metadata: &ColumnChunkMetaData,
+        offset_index: &OffsetIndex,
+        total_rows: usize,
+    ) -> ParquetResult<Self> {
+        let page_locations: VecDeque<_> = offset_index.page_locations.iter().copied().collect();
+
+        // The offset index only has the data pages, the dictionary page comes before them.
+        let (start, _) = metadata.byte_range();
+        let dictionary_page = page_locations
+            .front()
+            .filter(|first| first.offset > start)
+            .map(|first| PageLocation {
+                offset: start,
+                compressed_page_size: first.offset - start,
+                first_row_index: 0,
+            });
+
+        let state = PageReaderState::Pages {
+            page_locations,
+            dictionary_page,
+            total_rows,
+        };
+        Self::with_state(reader, metadata, state)
+    }
 
-        let decompressor = create_decompressor(metadata.compression);
-        SerPageReader {
+    fn with_state(
+        reader: R,
+        metadata: &ColumnChunkMetaData,
+        state: PageReaderState,
+    ) -> ParquetResult<Self> {
+        Ok(SerPageReader {
             reader,
-            physical_type: metadata.column_type,
-            decompressor,
+            column_descr: metadata.column_descr().clone(),
+            decompressor: create_decompressor(metadata.compression)?,
             state,
-        }
+        })
+    }
+
+    /// Reads and decodes the page at `location`.
+    fn read_page_at(&mut self, location: PageLocation) -> ParquetResult<Page> {
+        let bytes = self
+            .reader
+            .get_bytes(location.offset, location.compressed_page_size)?;
+        let (read, header) = read_page_header(bytes)?;
+        let data_len = header.compressed_page_size as usize;
+        let data = bytes.get(read..read + data_len).ok_or(ParquetError::EOF)?;
+        decode_page(
+            header,
+            data.to_vec(),
+            &self.column_descr,
+            self.decompressor.as_mut(),
+        )
     }
 }
 
@@ -61,7 +116,6 @@ impl<R: ParquetReader> Iterator for SerPageReader<R> {
 
 impl<R: ParquetReader> PageReader for SerPageReader<R> {
     fn get_next_page(&mut self) -> ParquetResult<Option<Page>> {
-        use std::io::Read;
         loop {
             match &mut self.state {
                 PageReaderState::Values {
@@ -72,63 +126,423 @@ impl<R: ParquetReader> PageReader for SerPageReader<R> {
                     if *remaining_bytes == 0 {
                         return Ok(None);
                     }
-                    dbg!(*offset, *remaining_bytes);
-
-                    let mut reader = self
-                        .reader
-                        .get_reader(*offset as usize, *remaining_bytes as usize)?;
 
-                    let header = next_page_header.take().map(Ok).unwrap_or_else(|| {
-                        let (read, header) = read_page_header(&mut reader)?;
-                        *offset += read;
-                        *remaining_bytes -= read;
-                        ParquetResult::Ok(header)
-                    })?;
+                    let header = match next_page_header.take() {
+                        Some(header) => *header,
+                        None => {
+                            let reader = self.reader.get_reader(*offset, *remaining_bytes)?;
+                            let (read, header) = read_page_header(reader)?;
+                            *offset += read;
+                            *remaining_bytes -= read;
+                            header
+                        }
+                    };
 
                     let data_len = header.compressed_page_size as usize;
+                    if data_len > *remaining_bytes {
+                        return Err(ParquetError::EOF);
+                    }
+                    let data = self.reader.get_bytes(*offset, data_len)?.to_vec();
                     *offset += data_len;
                     *remaining_bytes -= data_len;
 
-                    if header.type_.0 == PageType::IndexPage as i32 {
+                    // Index pages and pages of unknown types hold no values of the column.
+                    if matches!(
+                        header.type_.into(),
+                        PageType::IndexPage | PageType::Unknown(_)
+                    ) {
                         continue;
                     }
 
-                    let mut buffer = Vec::with_capacity(data_len);
-                    let read = reader.take(data_len as u64).read_to_end(&mut buffer)?;
+                    let page =
+                        decode_page(header, data, &self.column_descr, self.decompressor.as_mut())?;
+                    return Ok(Some(page));
+                }
+                PageReaderState::Pages {
+                    page_locations,
+                    dictionary_page,
+                    ..
+                } => {
+                    let location = match dictionary_page.take() {
+                        Some(location) => location,
+                        None => match page_locations.pop_front() {
+                            Some(location) => location,
+                            None => return Ok(None),
+                        },
+                    };
+                    return self.read_page_at(location).map(Some);
+                }
+            }
+        }
+    }
 
-                    if data_len != read {
-                        return Err(ParquetError::EOF);
+    fn peek_next_page(&mut self) -> ParquetResult<Option<PageMetadata>> {
+        loop {
+            match &mut self.state {
+                PageReaderState::Values {
+                    offset,
+                    remaining_bytes,
+                    next_page_header,
+                } => {
+                    if *remaining_bytes == 0 {
+                        return Ok(None);
                     }
 
-                    dbg!(header);
+                    let header = match next_page_header {
+                        Some(header) => header,
+                        None => {
+                            let reader = self.reader.get_reader(*offset, *remaining_bytes)?;
+                            let (read, header) = read_page_header(reader)?;
+                            *offset += read;
+                            *remaining_bytes -= read;
+                            next_page_header.insert(Box::new(header))
+                        }
+                    };
 
-                    todo!()
+                    let metadata = match header.type_.into() {
+                        PageType::DictionaryPage => PageMetadata {
+                            num_rows: 0,
+                            is_dict: true,
+                        },
+                        PageType::DataPageV2 => PageMetadata {
+                            num_rows: header
+                                .data_page_header_v2
+                                .as_ref()
+                                .map_or(0, |h| h.num_rows as u64),
+                            is_dict: false,
+                        },
+                        PageType::IndexPage | PageType::Unknown(_) => {
+                            self.skip_next_page()?;
+                            continue;
+                        }
+                        // The number of rows of a v1 data page is only known after decoding
+                        // its repetition levels.
+                        PageType::DataPageV1 => {
+                            return Err(ParquetError::NotSupported(
+                                "Peeking at a v1 data page requires the offset index".into(),
+                            ))
+                        }
+                    };
+                    return Ok(Some(metadata));
                 }
                 PageReaderState::Pages {
                     page_locations,
                     dictionary_page,
-                    ..
+                    total_rows,
                 } => {
-                    todo!()
+                    if dictionary_page.is_some() {
+                        return Ok(Some(PageMetadata {
+                            num_rows: 0,
+                            is_dict: true,
+                        }));
+                    }
+                    let Some(location) = page_locations.front() else {
+                        return Ok(None);
+                    };
+                    let end = page_locations
+                        .get(1)
+                        .map_or(*total_rows, |next| next.first_row_index);
+                    let num_rows = end.checked_sub(location.first_row_index).ok_or_else(|| {
+                        ParquetError::InvalidFormat(format!(
+                            "The page at offset {} starts at row {}, after the next page or \
+                             the end of the row group",
+                            location.offset, location.first_row_index
+                        ))
+                    })?;
+                    return Ok(Some(PageMetadata {
+                        num_rows: num_rows as u64,
+                        is_dict: false,
+                    }));
                 }
             }
         }
+    }
 
-        todo!()
+    fn skip_next_page(&mut self) -> ParquetResult<()> {
+        match &mut self.state {
+            PageReaderState::Values {
+                offset,
+                remaining_bytes,
+                next_page_header,
+            } => {
+                if *remaining_bytes == 0 {
+                    return Ok(());
+                }
+                let header = match next_page_header.take() {
+                    Some(header) => *header,
+                    None => {
+                        let reader = self.reader.get_reader(*offset, *remaining_bytes)?;
+                        let (read, header) = read_page_header(reader)?;
+                        *offset += read;
+                        *remaining_bytes -= read;
+                        header
+                    }
+                };
+                let data_len = header.compressed_page_size as usize;
+                if data_len > *remaining_bytes {
+                    return Err(ParquetError::EOF);
+                }
+                *offset += data_len;
+                *remaining_bytes -= data_len;
+            }
+            PageReaderState::Pages {
+                page_locations,
+                dictionary_page,
+                ..
+            } => {
+                if dictionary_page.take().is_none() {
+                    page_locations.pop_front();
+                }
+            }
+        }
+        Ok(())
     }
+}
 
-    fn peek_next_page(&mut self) -> ParquetResult<Option<PageMetadata>> {
-        todo!()
+/// Calls `f` with every page of a column chunk that contains rows of `selection`, and the
+/// index of its first row. The other data pages are skipped without being read. The
+/// dictionary page is always read.
+fn for_each_selected_page(
+    page_reader: &mut impl PageReader,
+    selection: &RowSelection,
+    mut f: impl FnMut(Page, usize) -> ParquetResult<()>,
+) -> ParquetResult<()> {
+    let mut first_row = 0;
+    while let Some(metadata) = page_reader.peek_next_page()? {
+        let num_rows = metadata.num_rows as usize;
+        if metadata.is_dict || selection.selects_any(first_row..first_row + num_rows) {
+            if let Some(page) = page_reader.get_next_page()? {
+                f(page, first_row)?;
+            }
+        } else {
+            page_reader.skip_next_page()?;
+        }
+        first_row += num_rows;
     }
+    Ok(())
+}
 
-    fn skip_next_page(&mut self) -> ParquetResult<()> {
-        todo!()
+/// Reads the pages of a column chunk that contain rows of `selection`. The other data
+/// pages are skipped without being read. The dictionary page is always read.
+pub(crate) fn read_selected_pages(
+    page_reader: &mut impl PageReader,
+    selection: &RowSelection,
+) -> ParquetResult<Vec<Page>> {
+    let mut pages = vec![];
+    for_each_selected_page(page_reader, selection, |page, _| {
+        pages.push(page);
+        Ok(())
+    })?;
+    Ok(pages)
+}
+
+/// Reads all pages of column `column_i` in a row group.
+///
+/// If the `OffsetIndex` of the column chunk and a `selection` are given, only the pages
+/// that contain selected rows are read.
+pub fn read_row_group<R: ParquetReader>(
+    reader: R,
+    metadata: &RowGroupMetaData,
+    column_i: usize,
+    offset_index: Option<&OffsetIndex>,
+    selection: Option<&RowSelection>,
+) -> ParquetResult<Vec<Page>> {
+    let column_md = &metadata.columns[column_i];
+    let num_rows = metadata.num_rows as usize;
+
+    match (offset_index, selection) {
+        (Some(offset_index), Some(selection)) => {
+            let mut page_reader =
+                SerPageReader::new_with_offset_index(reader, column_md, offset_index, num_rows)?;
+            read_selected_pages(&mut page_reader, selection)
+        }
+        _ => SerPageReader::new(reader, column_md)?.collect(),
     }
 }
 
-pub fn read_row_group<R: ParquetReader>(reader: R, metadata: &RowGroupMetaData, column_i: usize) {
+/// Reads and decodes column `column_i` of a row group.
+///
+/// If a `selection` is given, only the selected rows are returned. With the `OffsetIndex`
+/// of the column chunk, the pages without selected rows are skipped without being read,
+/// otherwise all pages are decoded and the rows that are not selected are dropped.
+pub fn read_column<R: ParquetReader>(
+    reader: R,
+    metadata: &RowGroupMetaData,
+    column_i: usize,
+    offset_index: Option<&OffsetIndex>,
+    selection: Option<&RowSelection>,
+) -> ParquetResult<ColumnData> {
     let column_md = &metadata.columns[column_i];
+    let num_rows = metadata.num_rows as usize;
+    let mut decoder = ColumnDecoder::new(column_md.column_descr());
+
+    match (offset_index, selection) {
+        (Some(offset_index), Some(selection)) => {
+            let mut page_reader =
+                SerPageReader::new_with_offset_index(reader, column_md, offset_index, num_rows)?;
+            for_each_selected_page(&mut page_reader, selection, |page, first_row| {
+                decoder
+                    .push_page(page, first_row, Some(selection))
+                    .map(|_| ())
+            })?;
+        }
+        _ => {
+            let mut first_row = 0;
+            for page in SerPageReader::new(reader, column_md)? {
+                first_row += decoder.push_page(page?, first_row, selection)?;
+            }
+        }
+    }
+    Ok(decoder.finish())
+}
 
-    let mut page_reader = SerPageReader::new(reader, column_md, 10);
-    page_reader.get_next_page();
+/// Reads and decodes the dictionary page of column `column_i` in a row group, `None` if
+/// the column chunk has no dictionary page. The data pages are not read.
+pub fn read_dictionary<R: ParquetReader>(
+    reader: R,
+    metadata: &RowGroupMetaData,
+    column_i: usize,
+) -> ParquetResult<Option<ColumnValues>> {
+    let column_md = &metadata.columns[column_i];
+    if column_md.dictionary_page_offset().is_none() {
+        return Ok(None);
+    }
+    match SerPageReader::new(reader, column_md)?.get_next_page()? {
+        Some(page) => decode_dictionary(&page, column_md.column_descr()),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::{get_metadata, read_page_index, Statistics, TOffsetIndex, TPageLocation};
+    use crate::test_util::{write_file, Float16, TestColumn, TestValues};
+
+    #[test]
+    fn test_skip_pruned_pages() {
+        let pages = (0..4)
+            .map(|page| (0..10).map(|i| Some(page * 10 + i)).collect())
+            .collect();
+        let file = write_file(vec![TestColumn::new("a", false, TestValues::Int32(pages))]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let (column_index, offset_index) =
+            read_page_index(file.as_slice(), &metadata, &[0], &[0]).unwrap();
+        let column_index = column_index[0][0].as_ref().unwrap();
+        let offset_index = offset_index[0][0].as_ref().unwrap();
+
+        // a >= 15 AND a < 25
+        let selection =
+            RowSelection::from_column_index(column_index, offset_index, 40, |stats| match stats {
+                Statistics::Int32(s) => s.max() >= Some(&15) && s.min() < Some(&25),
+                _ => unreachable!(),
+            })
+            .unwrap();
+        assert_eq!(selection.selected_row_count(), 20);
+
+        let rg = &metadata.row_groups[0];
+        let pages =
+            read_row_group(file.as_slice(), rg, 0, Some(offset_index), Some(&selection)).unwrap();
+        let page_mins = pages
+            .iter()
+            .map(|page| match page {
+                Page::Data {
+                    statistics: Some(Statistics::Int32(s)),
+                    ..
+                } => s.min().copied(),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(page_mins, vec![Some(10), Some(20)]);
+
+        let all_pages = read_row_group(file.as_slice(), rg, 0, None, None).unwrap();
+        assert_eq!(all_pages.len(), 4);
+    }
+
+    #[test]
+    fn test_corrupt_offset_index() {
+        let pages = (0..2)
+            .map(|page| (0..10).map(|i| Some(page * 10 + i)).collect())
+            .collect();
+        let file = write_file(vec![TestColumn::new("a", false, TestValues::Int32(pages))]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let (_, offset_index) = read_page_index(file.as_slice(), &metadata, &[0], &[0]).unwrap();
+        let mut offset_index = offset_index[0][0].clone().unwrap();
+        let column = &metadata.row_groups[0].columns[0];
+
+        // The second page starts after the end of the row group.
+        offset_index.page_locations[1].first_row_index = 25;
+        let mut page_reader =
+            SerPageReader::new_with_offset_index(file.as_slice(), column, &offset_index, 20)
+                .unwrap();
+        assert_eq!(page_reader.peek_next_page().unwrap().unwrap().num_rows, 25);
+        page_reader.skip_next_page().unwrap();
+        assert!(matches!(
+            page_reader.peek_next_page(),
+            Err(ParquetError::InvalidFormat(_))
+        ));
+
+        let locations = offset_index
+            .page_locations
+            .iter()
+            .rev()
+            .map(|location| TPageLocation {
+                offset: location.offset as i64,
+                compressed_page_size: location.compressed_page_size as i32,
+                first_row_index: location.first_row_index as i64,
+            })
+            .collect();
+        let decreasing = TOffsetIndex {
+            page_locations: locations,
+            unencoded_byte_array_data_bytes: None,
+        };
+        assert!(matches!(
+            OffsetIndex::from_thrift(decreasing),
+            Err(ParquetError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_float16_page_statistics() {
+        // 1.5, -2.0 and NaN, then 0.5 and null
+        let pages = vec![
+            vec![Some(0x3E00), Some(0xC000), Some(0x7E00)],
+            vec![Some(0x3800), None],
+        ];
+        let pages = pages
+            .into_iter()
+            .map(|page| page.into_iter().map(|v| v.map(Float16)).collect())
+            .collect();
+        let file = write_file(vec![TestColumn::new("a", true, TestValues::Float16(pages))]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+
+        let pages = read_row_group(file.as_slice(), &metadata.row_groups[0], 0, None, None);
+        let bounds = pages
+            .unwrap()
+            .iter()
+            .map(|page| match page {
+                Page::Data {
+                    statistics: Some(Statistics::Float(s)),
+                    ..
+                } => (s.min().copied(), s.max().copied()),
+                _ => panic!("expected FLOAT16 page statistics decoded to f32"),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bounds,
+            vec![(Some(-2.0), Some(1.5)), (Some(0.5), Some(0.5))]
+        );
+    }
+
+    #[test]
+    fn test_unknown_compression() {
+        let values = TestValues::Int32(vec![vec![Some(1), Some(2)]]);
+        let file = write_file(vec![TestColumn::new("a", false, values)]);
+        let mut metadata = get_metadata(file.as_slice()).unwrap();
+        let rg = &mut metadata.row_groups[0];
+        rg.columns[0].compression = crate::metadata::Compression::Unknown(42);
+
+        let result = read_column(file.as_slice(), rg, 0, None, None);
+        assert!(matches!(result, Err(ParquetError::NotSupported(_))));
+    }
 }
@@ -0,0 +1,322 @@
+use crate::errors::{ParquetError, ParquetResult};
+use crate::metadata::{ColumnIndex, OffsetIndex, Statistics};
+use std::ops::Range;
+
+/// A run of consecutive rows that are either all selected or all skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowSelector {
+    pub row_count: usize,
+    pub skip: bool,
+}
+
+impl RowSelector {
+    pub fn select(row_count: usize) -> Self {
+        RowSelector {
+            row_count,
+            skip: false,
+        }
+    }
+
+    pub fn skip(row_count: usize) -> Self {
+        RowSelector {
+            row_count,
+            skip: true,
+        }
+    }
+}
+
+/// A selection of the rows of a row group, stored as consecutive runs of selected and
+/// skipped rows.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RowSelection {
+    selectors: Vec<RowSelector>,
+}
+
+impl RowSelection {
+    /// Creates a selection from runs of selected and skipped rows. Adjacent runs of the
+    /// same kind are merged and empty runs are dropped.
+    pub fn from_selectors(selectors: impl IntoIterator<Item = RowSelector>) -> Self {
+        let mut out: Vec<RowSelector> = vec![];
+        for selector in selectors {
+            if selector.row_count == 0 {
+                continue;
+            }
+            match out.last_mut() {
+                Some(last) if last.skip == selector.skip => last.row_count += selector.row_count,
+                _ => out.push(selector),
+            }
+        }
+        RowSelection { selectors: out }
+    }
+
+    /// Selects the rows in `ranges` out of `total_rows` rows. The ranges must be sorted,
+    /// overlapping ranges are merged.
+    pub fn from_ranges(ranges: impl IntoIterator<Item = Range<usize>>, total_rows: usize) -> Self {
+        let mut selectors = vec![];
+        let mut end = 0;
+        for range in ranges {
+            let start = range.start.max(end);
+            let range_end = range.end.min(total_rows);
+            if start >= range_end {
+                continue;
+            }
+            selectors.push(RowSelector::skip(start - end));
+            selectors.push(RowSelector::select(range_end - start));
+            end = range_end;
+        }
+        selectors.push(RowSelector::skip(total_rows - end));
+        Self::from_selectors(selectors)
+    }
+
+    /// Selects all of `total_rows` rows except the ones in `deleted_rows`, e.g. the
+    /// positions of a deletion vector of a table format. The positions must be sorted.
+    pub fn from_deleted_rows(
+        deleted_rows: impl IntoIterator<Item = usize>,
+        total_rows: usize,
+    ) -> Self {
+        let ranges = deleted_rows.into_iter().map(|row| row..row + 1);
+        Self::from_ranges(ranges, total_rows).invert(total_rows)
+    }
+
+    /// Selects the rows of which `filter` is `true`.
+    pub fn from_filter(filter: &[bool]) -> Self {
+        Self::from_selectors(filter.iter().map(|keep| match keep {
+            true => RowSelector::select(1),
+            false => RowSelector::skip(1),
+        }))
+    }
+
+    /// Returns the selection of the first `row_count` rows and removes them from `self`.
+    /// This is used to split a selection over multiple row groups.
+    pub fn split_off(&mut self, row_count: usize) -> RowSelection {
+        let mut head = vec![];
+        let mut remaining = row_count;
+        let mut split_at = self.selectors.len();
+        for (i, selector) in self.selectors.iter_mut().enumerate() {
+            if remaining == 0 {
+                split_at = i;
+                break;
+            }
+            if selector.row_count > remaining {
+                head.push(RowSelector {
+                    row_count: remaining,
+                    skip: selector.skip,
+                });
+                selector.row_count -= remaining;
+                split_at = i;
+                break;
+            }
+            remaining -= selector.row_count;
+            head.push(*selector);
+        }
+        self.selectors.drain(..split_at);
+        RowSelection { selectors: head }
+    }
+
+    /// Returns the selection of the rows that are skipped by `self`, out of `total_rows`.
+    fn invert(&self, total_rows: usize) -> RowSelection {
+        let inverted = self.selectors.iter().map(|selector| RowSelector {
+            row_count: selector.row_count,
+            skip: !selector.skip,
+        });
+        let remaining = total_rows.saturating_sub(self.row_count());
+        Self::from_selectors(inverted.chain(std::iter::once(RowSelector::select(remaining))))
+    }
+
+    /// Selects the rows of the pages that may contain rows matching a predicate.
+    ///
+    /// `may_match` is called with the statistics of every page in `column_index`, and
+    /// should only return `false` if none of the rows in the page can match.
+    pub fn from_column_index(
+        column_index: &ColumnIndex,
+        offset_index: &OffsetIndex,
+        num_rows: usize,
+        may_match: impl Fn(&Statistics) -> bool,
+    ) -> ParquetResult<Self> {
+        let locations = &offset_index.page_locations;
+        if column_index.num_pages() != locations.len() {
+            return Err(ParquetError::InvalidFormat(format!(
+                "The column index has {} pages, but the offset index has {}",
+                column_index.num_pages(),
+                locations.len()
+            )));
+        }
+
+        let selectors = locations.iter().enumerate().map(|(i, location)| {
+            let end = locations
+                .get(i + 1)
+                .map_or(num_rows, |next| next.first_row_index);
+            let row_count = end.saturating_sub(location.first_row_index);
+            if may_match(&column_index.page_statistics(i)) {
+                RowSelector::select(row_count)
+            } else {
+                RowSelector::skip(row_count)
+            }
+        });
+        Ok(Self::from_selectors(selectors))
+    }
+
+    pub fn selectors(&self) -> &[RowSelector] {
+        &self.selectors
+    }
+
+    /// Returns the total number of rows covered by this selection, selected or not.
+    pub fn row_count(&self) -> usize {
+        self.selectors.iter().map(|s| s.row_count).sum()
+    }
+
+    /// Returns the number of selected rows.
+    pub fn selected_row_count(&self) -> usize {
+        self.selectors
+            .iter()
+            .filter(|s| !s.skip)
+            .map(|s| s.row_count)
+            .sum()
+    }
+
+    /// Returns the rows that are selected in both `self` and `other`.
+    /// Rows beyond the end of the shorter selection are skipped.
+    pub fn intersection(&self, other: &RowSelection) -> RowSelection {
+        let mut left = self.selectors.iter().copied();
+        let mut right = other.selectors.iter().copied();
+        let mut out = vec![];
+
+        let (mut l, mut r) = (left.next(), right.next());
+        while let (Some(mut ls), Some(mut rs)) = (l, r) {
+            let row_count = ls.row_count.min(rs.row_count);
+            out.push(RowSelector {
+                row_count,
+                skip: ls.skip || rs.skip,
+            });
+            ls.row_count -= row_count;
+            rs.row_count -= row_count;
+            l = if ls.row_count == 0 {
+                left.next()
+            } else {
+                Some(ls)
+            };
+            r = if rs.row_count == 0 {
+                right.next()
+            } else {
+                Some(rs)
+            };
+        }
+        Self::from_selectors(out)
+    }
+
+    /// Applies `other`, a selection of the rows selected by `self`, and returns the
+    /// resulting selection of the rows of `self`. Selected rows beyond the end of `other`
+    /// are skipped.
+    pub fn and_then(&self, other: &RowSelection) -> RowSelection {
+        let mut others = other.selectors.iter().copied();
+        let mut current = others.next();
+        let mut out = vec![];
+        for selector in &self.selectors {
+            if selector.skip {
+                out.push(*selector);
+                continue;
+            }
+            let mut remaining = selector.row_count;
+            while remaining > 0 {
+                let Some(other) = current.as_mut() else {
+                    out.push(RowSelector::skip(remaining));
+                    break;
+                };
+                let row_count = other.row_count.min(remaining);
+                out.push(RowSelector {
+                    row_count,
+                    skip: other.skip,
+                });
+                other.row_count -= row_count;
+                remaining -= row_count;
+                if other.row_count == 0 {
+                    current = others.next();
+                }
+            }
+        }
+        Self::from_selectors(out)
+    }
+
+    /// Returns for every row in `rows` whether it is selected.
+    /// Rows beyond the end of the selection are not selected.
+    pub(crate) fn row_mask(&self, rows: Range<usize>) -> Vec<bool> {
+        let mut mask = Vec::with_capacity(rows.len());
+        let mut start = 0;
+        for selector in &self.selectors {
+            let end = start + selector.row_count;
+            let overlap = end.min(rows.end).saturating_sub(start.max(rows.start));
+            mask.extend(std::iter::repeat_n(!selector.skip, overlap));
+            if end >= rows.end {
+                break;
+            }
+            start = end;
+        }
+        mask.resize(rows.len(), false);
+        mask
+    }
+
+    /// Whether any row in `rows` is selected.
+    pub fn selects_any(&self, rows: Range<usize>) -> bool {
+        let mut start = 0;
+        for selector in &self.selectors {
+            let end = start + selector.row_count;
+            if end > rows.start && start < rows.end && !selector.skip {
+                return true;
+            }
+            if end >= rows.end {
+                break;
+            }
+            start = end;
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_off() {
+        let mut selection = RowSelection::from_deleted_rows([2, 3, 7], 10);
+        assert_eq!(
+            selection.selectors(),
+            &[
+                RowSelector::select(2),
+                RowSelector::skip(2),
+                RowSelector::select(3),
+                RowSelector::skip(1),
+                RowSelector::select(2),
+            ]
+        );
+
+        let head = selection.split_off(3);
+        assert_eq!(
+            head.selectors(),
+            &[RowSelector::select(2), RowSelector::skip(1)]
+        );
+        let head = selection.split_off(5);
+        assert_eq!(
+            head.selectors(),
+            &[
+                RowSelector::skip(1),
+                RowSelector::select(3),
+                RowSelector::skip(1)
+            ]
+        );
+        assert_eq!(selection.selectors(), &[RowSelector::select(2)]);
+        assert_eq!(selection.row_mask(1..4), vec![true, false, false]);
+    }
+
+    #[test]
+    fn test_and_then() {
+        // rows 2..5 and 7..10 of 10
+        let selection = RowSelection::from_ranges([2..5, 7..10], 10);
+        // the 2nd, 3rd and 6th of the 6 selected rows
+        let filter = RowSelection::from_filter(&[false, true, true, false, false, true]);
+        assert_eq!(
+            selection.and_then(&filter),
+            RowSelection::from_ranges([3..5, 9..10], 10)
+        );
+    }
+}
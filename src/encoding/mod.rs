@@ -0,0 +1,37 @@
+//! Decoders of the encodings in [`Encoding`](crate::metadata::Encoding).
+pub(crate) mod plain;
+pub(crate) mod rle;
+
+use crate::errors::{ParquetError, ParquetResult};
+
+/// Reads an unsigned LEB128 encoded integer, returns it and the number of bytes read.
+pub(crate) fn decode_uleb128(data: &[u8]) -> ParquetResult<(u64, usize)> {
+    let mut value = 0u64;
+    for (i, byte) in data.iter().enumerate().take(10) {
+        value |= ((byte & 0x7F) as u64) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+    Err(ParquetError::InvalidFormat(
+        "Could not decode ULEB128 integer".into(),
+    ))
+}
+
+/// Returns the number of bits needed to store `max_value`.
+pub(crate) fn bit_width(max_value: u64) -> u32 {
+    64 - max_value.leading_zeros()
+}
+
+/// Splits off a 4 byte little endian length prefix and the bytes it covers.
+pub(crate) fn split_length_prefixed(data: &[u8]) -> ParquetResult<(&[u8], &[u8])> {
+    let length = data
+        .get(..4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()) as usize)
+        .ok_or(ParquetError::EOF)?;
+    let rest = &data[4..];
+    if length > rest.len() {
+        return Err(ParquetError::EOF);
+    }
+    Ok(rest.split_at(length))
+}
@@ -0,0 +1,74 @@
+use crate::errors::{ParquetError, ParquetResult};
+use crate::physical::{ByteArray, FixedLenByteArray, Int96, NativeType};
+
+fn not_enough_values(num_values: usize) -> ParquetError {
+    ParquetError::InvalidFormat(format!(
+        "Not enough bytes to decode {num_values} PLAIN encoded values"
+    ))
+}
+
+/// Decodes `num_values` booleans, bit packed with the least significant bit first.
+pub(crate) fn decode_bool(data: &[u8], num_values: usize) -> ParquetResult<Vec<bool>> {
+    if data.len() * 8 < num_values {
+        return Err(not_enough_values(num_values));
+    }
+    Ok((0..num_values)
+        .map(|i| (data[i / 8] >> (i % 8)) & 1 == 1)
+        .collect())
+}
+
+pub(crate) fn decode_native<T: NativeType>(
+    data: &[u8],
+    num_values: usize,
+) -> ParquetResult<Vec<T>> {
+    let size = std::mem::size_of::<T>();
+    let data = data
+        .get(..num_values * size)
+        .ok_or_else(|| not_enough_values(num_values))?;
+    Ok(data
+        .chunks_exact(size)
+        .map(|chunk| T::from_le_bytes(chunk.try_into().unwrap()))
+        .collect())
+}
+
+pub(crate) fn decode_int96(data: &[u8], num_values: usize) -> ParquetResult<Vec<Int96>> {
+    let data = data
+        .get(..num_values * 12)
+        .ok_or_else(|| not_enough_values(num_values))?;
+    Ok(data
+        .chunks_exact(12)
+        .map(|chunk| Int96::from_le_bytes(chunk.try_into().unwrap()))
+        .collect())
+}
+
+/// Decodes `num_values` byte arrays, each prefixed by its length as a 4 byte little endian integer.
+pub(crate) fn decode_byte_array(
+    mut data: &[u8],
+    num_values: usize,
+) -> ParquetResult<Vec<ByteArray>> {
+    let mut out = Vec::with_capacity(num_values);
+    for _ in 0..num_values {
+        let (value, rest) =
+            super::split_length_prefixed(data).map_err(|_| not_enough_values(num_values))?;
+        out.push(ByteArray::new(value.to_vec()));
+        data = rest;
+    }
+    Ok(out)
+}
+
+pub(crate) fn decode_fixed_len_byte_array(
+    data: &[u8],
+    type_length: usize,
+    num_values: usize,
+) -> ParquetResult<Vec<FixedLenByteArray>> {
+    if type_length == 0 {
+        return Ok(vec![FixedLenByteArray::default(); num_values]);
+    }
+    let data = data
+        .get(..num_values * type_length)
+        .ok_or_else(|| not_enough_values(num_values))?;
+    Ok(data
+        .chunks_exact(type_length)
+        .map(|chunk| FixedLenByteArray::new(chunk.to_vec()))
+        .collect())
+}
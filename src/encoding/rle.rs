@@ -0,0 +1,83 @@
+use super::decode_uleb128;
+use crate::errors::{ParquetError, ParquetResult};
+
+/// Reads the `i`th value of `bit_width` bits from LSB first bit packed `data`.
+#[inline]
+fn unpack(data: &[u8], bit_width: u32, i: usize) -> u32 {
+    let start = i * bit_width as usize;
+    let mut value = 0u32;
+    for bit in 0..bit_width as usize {
+        let pos = start + bit;
+        if (data[pos / 8] >> (pos % 8)) & 1 == 1 {
+            value |= 1 << bit;
+        }
+    }
+    value
+}
+
+/// Decodes `num_values` values of the RLE/bit-packing hybrid encoding.
+///
+/// The encoding consists of runs, each starting with a ULEB128 header. If the lowest bit of
+/// the header is set, the run holds `(header >> 1) * 8` bit packed values. Otherwise it
+/// is a single value, stored in `ceil(bit_width / 8)` bytes, repeated `header >> 1` times.
+pub(crate) fn decode_hybrid_rle(
+    mut data: &[u8],
+    bit_width: u32,
+    num_values: usize,
+) -> ParquetResult<Vec<u32>> {
+    let mut out = Vec::with_capacity(num_values);
+    if bit_width == 0 {
+        out.resize(num_values, 0);
+        return Ok(out);
+    }
+    if bit_width > 32 {
+        return Err(ParquetError::InvalidFormat(format!(
+            "Invalid bit width {bit_width} for the RLE/bit-packing hybrid encoding"
+        )));
+    }
+
+    while out.len() < num_values {
+        let (header, read) = decode_uleb128(data)?;
+        data = &data[read..];
+
+        if header & 1 == 1 {
+            let num_packed = (header >> 1) as usize * 8;
+            let num_bytes = (num_packed * bit_width as usize).div_ceil(8);
+            let packed = data.get(..num_bytes).ok_or(ParquetError::EOF)?;
+            let take = num_packed.min(num_values - out.len());
+            out.extend((0..take).map(|i| unpack(packed, bit_width, i)));
+            data = &data[num_bytes..];
+        } else {
+            let run_length = (header >> 1) as usize;
+            let num_bytes = (bit_width as usize).div_ceil(8);
+            let bytes = data.get(..num_bytes).ok_or(ParquetError::EOF)?;
+            let mut le = [0u8; 4];
+            le[..num_bytes].copy_from_slice(bytes);
+            let value = u32::from_le_bytes(le);
+            let take = run_length.min(num_values - out.len());
+            out.extend(std::iter::repeat_n(value, take));
+            data = &data[num_bytes..];
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_hybrid_rle() {
+        // RLE run of 3 times 4, followed by a bit packed run of 8 values of 3 bits.
+        let data = [
+            0b0000_0110,
+            0b0000_0100,
+            0b0000_0011,
+            0b1000_1000,
+            0b1100_0110,
+            0b1111_1010,
+        ];
+        let values = decode_hybrid_rle(&data, 3, 10).unwrap();
+        assert_eq!(values, vec![4, 4, 4, 0, 1, 2, 3, 4, 5, 6]);
+    }
+}
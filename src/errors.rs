@@ -5,6 +5,8 @@ use std::fmt::{Display, Formatter};
 pub enum ParquetError {
     IO(String),
     InvalidFormat(String),
+    /// The file uses a feature that is not (yet) supported by this crate.
+    NotSupported(String),
     EOF,
 }
 pub type ParquetResult<T> = Result<T, ParquetError>;
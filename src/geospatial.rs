@@ -0,0 +1,129 @@
+//! Bounding boxes of geospatial values in the Well-Known Binary (WKB) format, as stored
+//! in GEOMETRY and GEOGRAPHY columns.
+use crate::errors::{ParquetError, ParquetResult};
+use crate::metadata::BoundingBox;
+
+/// Returns the bounding box of the vertices of a WKB geometry, `None` if it is empty.
+///
+/// Both ISO WKB and the Z, M and SRID flags of extended WKB are supported.
+pub(crate) fn wkb_bounding_box(wkb: &[u8]) -> ParquetResult<Option<BoundingBox>> {
+    let mut reader = WkbReader {
+        data: wkb,
+        offset: 0,
+    };
+    let mut bbox = None;
+    reader.geometry(&mut bbox)?;
+    Ok(bbox)
+}
+
+struct WkbReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl WkbReader<'_> {
+    fn bytes<const N: usize>(&mut self) -> ParquetResult<[u8; N]> {
+        let bytes = self
+            .data
+            .get(self.offset..self.offset + N)
+            .ok_or_else(|| ParquetError::InvalidFormat("Unexpected end of a WKB value".into()))?;
+        self.offset += N;
+        Ok(bytes.try_into().unwrap())
+    }
+
+    fn u32(&mut self, little_endian: bool) -> ParquetResult<u32> {
+        let bytes = self.bytes()?;
+        Ok(if little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self, little_endian: bool) -> ParquetResult<f64> {
+        let bytes = self.bytes()?;
+        Ok(if little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn geometry(&mut self, bbox: &mut Option<BoundingBox>) -> ParquetResult<()> {
+        let little_endian = match self.bytes::<1>()?[0] {
+            0 => false,
+            1 => true,
+            order => {
+                return Err(ParquetError::InvalidFormat(format!(
+                    "Invalid WKB byte order {order}"
+                )))
+            }
+        };
+        let code = self.u32(little_endian)?;
+        // ISO WKB adds 1000 for Z, 2000 for M and 3000 for ZM, extended WKB sets flags.
+        let (base, dimensions) = ((code & 0xFFFF) % 1000, (code & 0xFFFF) / 1000);
+        let has_z = dimensions == 1 || dimensions == 3 || code & 0x8000_0000 != 0;
+        let has_m = dimensions == 2 || dimensions == 3 || code & 0x4000_0000 != 0;
+        if code & 0x2000_0000 != 0 {
+            // The SRID of extended WKB.
+            self.u32(little_endian)?;
+        }
+        let mut points = |reader: &mut Self, count: u32| {
+            (0..count).try_for_each(|_| reader.point(little_endian, has_z, has_m, bbox))
+        };
+        match base {
+            1 => points(self, 1),
+            2 => {
+                let count = self.u32(little_endian)?;
+                points(self, count)
+            }
+            3 => {
+                let rings = self.u32(little_endian)?;
+                for _ in 0..rings {
+                    let count = self.u32(little_endian)?;
+                    points(self, count)?;
+                }
+                Ok(())
+            }
+            // Multi geometries and collections of whole WKB geometries.
+            4..=7 => {
+                let count = self.u32(little_endian)?;
+                (0..count).try_for_each(|_| self.geometry(bbox))
+            }
+            _ => Err(ParquetError::NotSupported(format!(
+                "WKB geometry type {code}"
+            ))),
+        }
+    }
+
+    fn point(
+        &mut self,
+        little_endian: bool,
+        has_z: bool,
+        has_m: bool,
+        bbox: &mut Option<BoundingBox>,
+    ) -> ParquetResult<()> {
+        let x = self.f64(little_endian)?;
+        let y = self.f64(little_endian)?;
+        let z = has_z.then(|| self.f64(little_endian)).transpose()?;
+        let m = has_m.then(|| self.f64(little_endian)).transpose()?;
+        // An empty point has NaN coordinates.
+        if x.is_nan() || y.is_nan() {
+            return Ok(());
+        }
+        let bbox = bbox.get_or_insert(BoundingBox::new(x, x, y, y));
+        bbox.xmin = bbox.xmin.min(x);
+        bbox.xmax = bbox.xmax.max(x);
+        bbox.ymin = bbox.ymin.min(y);
+        bbox.ymax = bbox.ymax.max(y);
+        if let Some(z) = z.filter(|z| !z.is_nan()) {
+            bbox.zmin = Some(bbox.zmin.map_or(z, |min| min.min(z)));
+            bbox.zmax = Some(bbox.zmax.map_or(z, |max| max.max(z)));
+        }
+        if let Some(m) = m.filter(|m| !m.is_nan()) {
+            bbox.mmin = Some(bbox.mmin.map_or(m, |min| min.min(m)));
+            bbox.mmax = Some(bbox.mmax.map_or(m, |max| max.max(m)));
+        }
+        Ok(())
+    }
+}
@@ -1,14 +1,28 @@
+pub mod aggregate;
+pub mod bloom_filter;
 mod compression;
 mod data;
+mod encoding;
 mod errors;
-pub(crate) mod metadata;
-mod physical;
+mod geospatial;
+pub mod metadata;
+pub mod physical;
+mod predicate;
 mod reader;
+#[cfg(test)]
+mod test_util;
+pub mod variant;
 
 pub(crate) static MAGIC_NUMBER: &[u8; 4] = b"PAR1";
 pub(crate) const FOOTER_SIZE: usize = 8;
 
-pub use data::read_row_group;
+pub use data::{
+    read_column, read_row_group, ColumnData, ColumnValues, FileReader, Page, RowGroupBatch,
+    RowSelection, RowSelector,
+};
+pub use errors::{ParquetError, ParquetResult};
+pub use predicate::{CompareOp, Outcomes, Predicate, ScalarValue};
+pub use reader::ParquetReader;
 
 #[cfg(test)]
 mod tests {
@@ -26,6 +40,6 @@ mod tests {
 
         let metadata = get_metadata(buf.as_slice()).unwrap();
 
-        read_row_group(buf.as_slice(), &metadata.row_groups[0], 0);
+        read_row_group(buf.as_slice(), &metadata.row_groups[0], 0, None, None).unwrap();
     }
 }
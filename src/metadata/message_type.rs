@@ -0,0 +1,663 @@
+//! The text form of a schema, as printed by parquet-mr and parquet-cpp:
+//!
+//! ```text
+//! message schema {
+//!   required int64 id (INTEGER(64,true)) = 1;
+//!   optional group tags (LIST) = 2 {
+//!     repeated group list {
+//!       optional binary element (STRING);
+//!     }
+//!   }
+//!   optional fixed_len_byte_array(16) price (DECIMAL(30,2));
+//! }
+//! ```
+//!
+//! Annotations are logical types, or converted types for the types without a logical
+//! type such as `UTF8` and `INT_8`. Field ids follow `=`.
+use super::parquet_format::{
+    EdgeInterpolationAlgorithm, MicroSeconds, MilliSeconds, NanoSeconds, TimeUnit,
+};
+use super::types::{ParquetType, TypeInfo};
+use super::{ConvertedType, LogicalType, PhysicalType, Repetition};
+use crate::errors::{ParquetError, ParquetResult};
+use std::fmt;
+
+/// Prints the schema in the message type text form, which [`parse_message_type`] parses.
+///
+/// The root group prints as a `message`, any other type as a field.
+impl fmt::Display for ParquetType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        print_type(f, self, 0)
+    }
+}
+
+fn print_type(f: &mut fmt::Formatter<'_>, tp: &ParquetType, indent: usize) -> fmt::Result {
+    write!(f, "{:indent$}", "")?;
+    let info = match tp {
+        ParquetType::Primitive { info, .. } | ParquetType::Group { info, .. } => info,
+    };
+    match info.repetition {
+        Some(repetition) => write!(f, "{} ", repetition_name(repetition))?,
+        None => write!(f, "message ")?,
+    }
+    match tp {
+        ParquetType::Primitive {
+            physical,
+            type_length,
+            ..
+        } => {
+            write!(f, "{}", physical_name(*physical))?;
+            if *physical == PhysicalType::FixedLenByteArray {
+                write!(f, "({type_length})")?;
+            }
+            write!(f, " ")?;
+        }
+        ParquetType::Group { .. } if info.repetition.is_some() => write!(f, "group ")?,
+        ParquetType::Group { .. } => {}
+    }
+    write!(f, "{}", info.name)?;
+    if let Some(annotation) = annotation(tp) {
+        write!(f, " ({annotation})")?;
+    }
+    if let Some(id) = info.id {
+        write!(f, " = {id}")?;
+    }
+    match tp {
+        ParquetType::Primitive { .. } => writeln!(f, ";"),
+        ParquetType::Group { fields, .. } => {
+            writeln!(f, " {{")?;
+            for field in fields {
+                print_type(f, field, indent + 2)?;
+            }
+            writeln!(f, "{:indent$}}}", "")
+        }
+    }
+}
+
+fn repetition_name(repetition: Repetition) -> &'static str {
+    match repetition {
+        Repetition::Required => "required",
+        Repetition::Optional => "optional",
+        Repetition::Repeated => "repeated",
+    }
+}
+
+fn physical_name(physical: PhysicalType) -> &'static str {
+    match physical {
+        PhysicalType::Boolean => "boolean",
+        PhysicalType::Int32 => "int32",
+        PhysicalType::Int64 => "int64",
+        PhysicalType::Int96 => "int96",
+        PhysicalType::Float => "float",
+        PhysicalType::Double => "double",
+        PhysicalType::ByteArray => "binary",
+        PhysicalType::FixedLenByteArray => "fixed_len_byte_array",
+    }
+}
+
+fn time_unit_name(unit: &TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::MILLIS(_) => "MILLIS",
+        TimeUnit::MICROS(_) => "MICROS",
+        TimeUnit::NANOS(_) => "NANOS",
+    }
+}
+
+/// Returns the annotation of a type, the logical type if it has one.
+fn annotation(tp: &ParquetType) -> Option<String> {
+    let info = match tp {
+        ParquetType::Primitive { info, .. } | ParquetType::Group { info, .. } => info,
+    };
+    if let Some(logical_type) = &info.logical_type {
+        return Some(match logical_type {
+            LogicalType::String => "STRING".into(),
+            LogicalType::Map => "MAP".into(),
+            LogicalType::List => "LIST".into(),
+            LogicalType::Enum => "ENUM".into(),
+            LogicalType::Decimal { scale, precision } => format!("DECIMAL({precision},{scale})"),
+            LogicalType::Date => "DATE".into(),
+            LogicalType::Time {
+                is_adjusted_to_utc,
+                unit,
+            } => format!("TIME({},{is_adjusted_to_utc})", time_unit_name(unit)),
+            LogicalType::Timestamp {
+                is_adjusted_to_utc,
+                unit,
+            } => format!("TIMESTAMP({},{is_adjusted_to_utc})", time_unit_name(unit)),
+            LogicalType::Integer {
+                bit_width,
+                is_signed,
+            } => format!("INTEGER({bit_width},{is_signed})"),
+            LogicalType::Unknown => "UNKNOWN".into(),
+            LogicalType::Json => "JSON".into(),
+            LogicalType::Bson => "BSON".into(),
+            LogicalType::Uuid => "UUID".into(),
+            LogicalType::Float16 => "FLOAT16".into(),
+            LogicalType::Variant {
+                specification_version: None,
+            } => "VARIANT".into(),
+            LogicalType::Variant {
+                specification_version: Some(version),
+            } => format!("VARIANT({version})"),
+            LogicalType::Geometry { crs: None } => "GEOMETRY".into(),
+            LogicalType::Geometry { crs: Some(crs) } => format!("GEOMETRY({crs})"),
+            LogicalType::Geography {
+                crs: None,
+                algorithm: EdgeInterpolationAlgorithm::SPHERICAL,
+            } => "GEOGRAPHY".into(),
+            LogicalType::Geography { crs, algorithm } => {
+                // The CRS defaults to OGC:CRS84, so it is written out to give the algorithm.
+                let crs = crs.as_deref().unwrap_or("OGC:CRS84");
+                match algorithm_name(*algorithm) {
+                    Some(name) => format!("GEOGRAPHY({crs},{name})"),
+                    None => format!("GEOGRAPHY({crs},{})", algorithm.0),
+                }
+            }
+        });
+    }
+    let name = match info.converted_type? {
+        ConvertedType::Utf8 => "UTF8",
+        ConvertedType::Map => "MAP",
+        ConvertedType::MapKeyValue => "MAP_KEY_VALUE",
+        ConvertedType::List => "LIST",
+        ConvertedType::Enum => "ENUM",
+        ConvertedType::Decimal => {
+            let ParquetType::Primitive {
+                scale, precision, ..
+            } = tp
+            else {
+                return Some("DECIMAL".into());
+            };
+            return Some(format!("DECIMAL({precision},{scale})"));
+        }
+        ConvertedType::Date => "DATE",
+        ConvertedType::TimeMillis => "TIME_MILLIS",
+        ConvertedType::TimeMicros => "TIME_MICROS",
+        ConvertedType::TimeStampMillis => "TIMESTAMP_MILLIS",
+        ConvertedType::TimeStampMicros => "TIMESTAMP_MICROS",
+        ConvertedType::Int8 => "INT_8",
+        ConvertedType::Int16 => "INT_16",
+        ConvertedType::Int32 => "INT_32",
+        ConvertedType::Int64 => "INT_64",
+        ConvertedType::UInt8 => "UINT_8",
+        ConvertedType::UInt16 => "UINT_16",
+        ConvertedType::UInt32 => "UINT_32",
+        ConvertedType::UInt64 => "UINT_64",
+        ConvertedType::Json => "JSON",
+        ConvertedType::Bson => "BSON",
+        ConvertedType::Interval => "INTERVAL",
+        ConvertedType::Unknown(_) => return None,
+    };
+    Some(name.into())
+}
+
+fn algorithm_name(algorithm: EdgeInterpolationAlgorithm) -> Option<&'static str> {
+    Some(match algorithm {
+        EdgeInterpolationAlgorithm::SPHERICAL => "SPHERICAL",
+        EdgeInterpolationAlgorithm::VINCENTY => "VINCENTY",
+        EdgeInterpolationAlgorithm::THOMAS => "THOMAS",
+        EdgeInterpolationAlgorithm::ANDOYER => "ANDOYER",
+        EdgeInterpolationAlgorithm::KARNEY => "KARNEY",
+        _ => return None,
+    })
+}
+
+/// Parses a schema in the message type text form, see the [`Display`](fmt::Display) of
+/// [`ParquetType`]. Keywords and annotations are case insensitive.
+///
+/// A logical type annotation also sets the matching converted type, like writers do.
+pub fn parse_message_type(message_type: &str) -> ParquetResult<ParquetType> {
+    let mut parser = Parser {
+        tokens: tokenize(message_type),
+        next: 0,
+    };
+    parser.expect("message")?;
+    let name = parser.name()?;
+    let fields = parser.fields()?;
+    if let Some(token) = parser.tokens.get(parser.next) {
+        return Err(invalid(format!("unexpected '{token}' after the message")));
+    }
+    Ok(ParquetType::Group {
+        info: TypeInfo {
+            name,
+            repetition: None,
+            converted_type: None,
+            logical_type: None,
+            id: None,
+        },
+        fields,
+    })
+}
+
+fn invalid(message: String) -> ParquetError {
+    ParquetError::InvalidFormat(format!("Invalid message type: {message}"))
+}
+
+/// Splits the text into words and the punctuation `{ } ( ) ; , =`.
+fn tokenize(text: &str) -> Vec<&str> {
+    let mut tokens = vec![];
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        let is_punctuation = "{}();,=".contains(c);
+        if c.is_whitespace() || is_punctuation {
+            if let Some(start) = start.take() {
+                tokens.push(&text[start..i]);
+            }
+            if is_punctuation {
+                tokens.push(&text[i..i + 1]);
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(start) = start {
+        tokens.push(&text[start..]);
+    }
+    tokens
+}
+
+struct Parser<'a> {
+    tokens: Vec<&'a str>,
+    next: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn token(&mut self) -> ParquetResult<&'a str> {
+        let token = self
+            .tokens
+            .get(self.next)
+            .ok_or_else(|| invalid("unexpected end".into()))?;
+        self.next += 1;
+        Ok(token)
+    }
+
+    /// Consumes the next token if it is `token`.
+    fn consume(&mut self, token: &str) -> bool {
+        let matches = self
+            .tokens
+            .get(self.next)
+            .is_some_and(|next| next.eq_ignore_ascii_case(token));
+        if matches {
+            self.next += 1;
+        }
+        matches
+    }
+
+    fn expect(&mut self, expected: &str) -> ParquetResult<()> {
+        let token = self.token()?;
+        if !token.eq_ignore_ascii_case(expected) {
+            return Err(invalid(format!("expected '{expected}', found '{token}'")));
+        }
+        Ok(())
+    }
+
+    fn name(&mut self) -> ParquetResult<String> {
+        let token = self.token()?;
+        if token.len() == 1 && "{}();,=".contains(token) {
+            return Err(invalid(format!("expected a name, found '{token}'")));
+        }
+        Ok(token.to_string())
+    }
+
+    fn number<T: std::str::FromStr>(&mut self) -> ParquetResult<T> {
+        let token = self.token()?;
+        token
+            .parse()
+            .map_err(|_| invalid(format!("expected a number, found '{token}'")))
+    }
+
+    /// Parses the fields of a group, between braces.
+    fn fields(&mut self) -> ParquetResult<Vec<ParquetType>> {
+        self.expect("{")?;
+        let mut fields = vec![];
+        while !self.consume("}") {
+            fields.push(self.field()?);
+        }
+        Ok(fields)
+    }
+
+    fn field(&mut self) -> ParquetResult<ParquetType> {
+        let repetition = match self.token()?.to_ascii_lowercase().as_str() {
+            "required" => Repetition::Required,
+            "optional" => Repetition::Optional,
+            "repeated" => Repetition::Repeated,
+            other => return Err(invalid(format!("expected a repetition, found '{other}'"))),
+        };
+        let kind = self.token()?.to_ascii_lowercase();
+        let physical = match kind.as_str() {
+            "group" => None,
+            "boolean" => Some(PhysicalType::Boolean),
+            "int32" => Some(PhysicalType::Int32),
+            "int64" => Some(PhysicalType::Int64),
+            "int96" => Some(PhysicalType::Int96),
+            "float" => Some(PhysicalType::Float),
+            "double" => Some(PhysicalType::Double),
+            "binary" => Some(PhysicalType::ByteArray),
+            "fixed_len_byte_array" => Some(PhysicalType::FixedLenByteArray),
+            other => return Err(invalid(format!("unknown type '{other}'"))),
+        };
+        let type_length = if physical == Some(PhysicalType::FixedLenByteArray) {
+            self.expect("(")?;
+            let length = self.number()?;
+            if length <= 0 {
+                return Err(invalid(format!(
+                    "invalid length {length} of fixed_len_byte_array"
+                )));
+            }
+            self.expect(")")?;
+            length
+        } else {
+            -1
+        };
+        let name = self.name()?;
+        let annotation = if self.consume("(") {
+            self.annotation()?
+        } else {
+            Annotation::default()
+        };
+        let id = self.consume("=").then(|| self.number()).transpose()?;
+
+        let info = TypeInfo {
+            name,
+            repetition: Some(repetition),
+            converted_type: annotation.converted_type,
+            logical_type: annotation.logical_type,
+            id,
+        };
+        match physical {
+            Some(physical) => {
+                self.expect(";")?;
+                Ok(ParquetType::Primitive {
+                    info,
+                    physical,
+                    type_length,
+                    scale: annotation.scale,
+                    precision: annotation.precision,
+                })
+            }
+            None => Ok(ParquetType::Group {
+                info,
+                fields: self.fields()?,
+            }),
+        }
+    }
+
+    /// Parses an annotation after its opening parenthesis.
+    fn annotation(&mut self) -> ParquetResult<Annotation> {
+        let name = self.token()?.to_ascii_uppercase();
+        let mut args = vec![];
+        if self.consume("(") {
+            while !self.consume(")") {
+                if !args.is_empty() {
+                    self.expect(",")?;
+                }
+                args.push(self.token()?);
+            }
+        }
+        self.expect(")")?;
+
+        let arg = |i: usize| -> ParquetResult<&str> {
+            args.get(i)
+                .copied()
+                .ok_or_else(|| invalid(format!("missing argument {} of {name}", i + 1)))
+        };
+        let number = |i: usize| -> ParquetResult<i32> {
+            let arg = arg(i)?;
+            arg.parse()
+                .map_err(|_| invalid(format!("expected a number in {name}, found '{arg}'")))
+        };
+        let boolean = |i: usize| -> ParquetResult<bool> {
+            match arg(i)?.to_ascii_lowercase().as_str() {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                other => Err(invalid(format!(
+                    "expected a boolean in {name}, found '{other}'"
+                ))),
+            }
+        };
+        let time_unit = |i: usize| -> ParquetResult<TimeUnit> {
+            match arg(i)?.to_ascii_uppercase().as_str() {
+                "MILLIS" => Ok(TimeUnit::MILLIS(MilliSeconds {})),
+                "MICROS" => Ok(TimeUnit::MICROS(MicroSeconds {})),
+                "NANOS" => Ok(TimeUnit::NANOS(NanoSeconds {})),
+                other => Err(invalid(format!("unknown time unit '{other}'"))),
+            }
+        };
+
+        let converted = |converted_type| Annotation {
+            converted_type: Some(converted_type),
+            ..Default::default()
+        };
+        let annotation = match name.as_str() {
+            "STRING" => LogicalType::String.into(),
+            "MAP" => LogicalType::Map.into(),
+            "LIST" => LogicalType::List.into(),
+            "ENUM" => LogicalType::Enum.into(),
+            "DECIMAL" => {
+                let (precision, scale) = (number(0)?, args.get(1).map_or(Ok(0), |_| number(1))?);
+                if precision <= 0 {
+                    return Err(invalid(format!("invalid precision {precision} of DECIMAL")));
+                }
+                if scale < 0 || scale > precision {
+                    return Err(invalid(format!(
+                        "invalid scale {scale} of DECIMAL with precision {precision}"
+                    )));
+                }
+                Annotation {
+                    precision,
+                    scale,
+                    ..LogicalType::Decimal { scale, precision }.into()
+                }
+            }
+            "DATE" => LogicalType::Date.into(),
+            "TIME" => LogicalType::Time {
+                unit: time_unit(0)?,
+                is_adjusted_to_utc: boolean(1)?,
+            }
+            .into(),
+            "TIMESTAMP" => LogicalType::Timestamp {
+                unit: time_unit(0)?,
+                is_adjusted_to_utc: boolean(1)?,
+            }
+            .into(),
+            "INTEGER" => {
+                let bit_width = match number(0)? {
+                    width @ (8 | 16 | 32 | 64) => width as i8,
+                    width => return Err(invalid(format!("invalid bit width {width} of INTEGER"))),
+                };
+                LogicalType::Integer {
+                    bit_width,
+                    is_signed: boolean(1)?,
+                }
+                .into()
+            }
+            "UNKNOWN" => LogicalType::Unknown.into(),
+            "JSON" => LogicalType::Json.into(),
+            "BSON" => LogicalType::Bson.into(),
+            "UUID" => LogicalType::Uuid.into(),
+            "FLOAT16" => LogicalType::Float16.into(),
+            "VARIANT" => {
+                let specification_version = args
+                    .first()
+                    .map(|_| {
+                        let version = number(0)?;
+                        i8::try_from(version).map_err(|_| {
+                            invalid(format!(
+                                "invalid specification version {version} of VARIANT"
+                            ))
+                        })
+                    })
+                    .transpose()?;
+                LogicalType::Variant {
+                    specification_version,
+                }
+                .into()
+            }
+            "GEOMETRY" => LogicalType::Geometry {
+                crs: args.first().map(|crs| crs.to_string()),
+            }
+            .into(),
+            "GEOGRAPHY" => LogicalType::Geography {
+                crs: args.first().map(|crs| crs.to_string()),
+                algorithm: match args.get(1) {
+                    None => EdgeInterpolationAlgorithm::SPHERICAL,
+                    Some(algorithm) => algorithm_by_name(algorithm)?,
+                },
+            }
+            .into(),
+            "UTF8" => converted(ConvertedType::Utf8),
+            "MAP_KEY_VALUE" => converted(ConvertedType::MapKeyValue),
+            "TIME_MILLIS" => converted(ConvertedType::TimeMillis),
+            "TIME_MICROS" => converted(ConvertedType::TimeMicros),
+            "TIMESTAMP_MILLIS" => converted(ConvertedType::TimeStampMillis),
+            "TIMESTAMP_MICROS" => converted(ConvertedType::TimeStampMicros),
+            "INT_8" => converted(ConvertedType::Int8),
+            "INT_16" => converted(ConvertedType::Int16),
+            "INT_32" => converted(ConvertedType::Int32),
+            "INT_64" => converted(ConvertedType::Int64),
+            "UINT_8" => converted(ConvertedType::UInt8),
+            "UINT_16" => converted(ConvertedType::UInt16),
+            "UINT_32" => converted(ConvertedType::UInt32),
+            "UINT_64" => converted(ConvertedType::UInt64),
+            "INTERVAL" => converted(ConvertedType::Interval),
+            other => return Err(invalid(format!("unknown annotation '{other}'"))),
+        };
+        Ok(annotation)
+    }
+}
+
+fn algorithm_by_name(name: &str) -> ParquetResult<EdgeInterpolationAlgorithm> {
+    EdgeInterpolationAlgorithm::ENUM_VALUES
+        .iter()
+        .copied()
+        .find(|algorithm| algorithm_name(*algorithm).is_some_and(|n| n.eq_ignore_ascii_case(name)))
+        .map_or_else(|| name.parse().map(EdgeInterpolationAlgorithm), Ok)
+        .map_err(|_| invalid(format!("unknown edge interpolation algorithm '{name}'")))
+}
+
+/// The types that an annotation sets, and the precision and scale of a decimal.
+struct Annotation {
+    logical_type: Option<LogicalType>,
+    converted_type: Option<ConvertedType>,
+    precision: i32,
+    scale: i32,
+}
+
+impl Default for Annotation {
+    fn default() -> Self {
+        Annotation {
+            logical_type: None,
+            converted_type: None,
+            precision: -1,
+            scale: -1,
+        }
+    }
+}
+
+impl From<LogicalType> for Annotation {
+    fn from(logical_type: LogicalType) -> Self {
+        let converted_type = match &logical_type {
+            LogicalType::String => Some(ConvertedType::Utf8),
+            LogicalType::Map => Some(ConvertedType::Map),
+            LogicalType::List => Some(ConvertedType::List),
+            LogicalType::Enum => Some(ConvertedType::Enum),
+            LogicalType::Decimal { .. } => Some(ConvertedType::Decimal),
+            LogicalType::Date => Some(ConvertedType::Date),
+            LogicalType::Time { unit, .. } => match unit {
+                TimeUnit::MILLIS(_) => Some(ConvertedType::TimeMillis),
+                TimeUnit::MICROS(_) => Some(ConvertedType::TimeMicros),
+                TimeUnit::NANOS(_) => None,
+            },
+            LogicalType::Timestamp { unit, .. } => match unit {
+                TimeUnit::MILLIS(_) => Some(ConvertedType::TimeStampMillis),
+                TimeUnit::MICROS(_) => Some(ConvertedType::TimeStampMicros),
+                TimeUnit::NANOS(_) => None,
+            },
+            LogicalType::Integer {
+                bit_width,
+                is_signed,
+            } => match (bit_width, is_signed) {
+                (8, true) => Some(ConvertedType::Int8),
+                (16, true) => Some(ConvertedType::Int16),
+                (32, true) => Some(ConvertedType::Int32),
+                (64, true) => Some(ConvertedType::Int64),
+                (8, false) => Some(ConvertedType::UInt8),
+                (16, false) => Some(ConvertedType::UInt16),
+                (32, false) => Some(ConvertedType::UInt32),
+                (64, false) => Some(ConvertedType::UInt64),
+                _ => None,
+            },
+            LogicalType::Json => Some(ConvertedType::Json),
+            LogicalType::Bson => Some(ConvertedType::Bson),
+            _ => None,
+        };
+        Annotation {
+            logical_type: Some(logical_type),
+            converted_type,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_print_and_parse() {
+        let message_type = "message schema {
+  required int64 id (INTEGER(64,true)) = 1;
+  optional group tags (LIST) = 2 {
+    repeated group list {
+      optional binary element (STRING);
+    }
+  }
+  optional fixed_len_byte_array(16) price (DECIMAL(30,2));
+  optional int32 legacy (UINT_16);
+  optional int64 ts (TIMESTAMP(NANOS,false));
+  optional binary shape (GEOGRAPHY(OGC:CRS84,KARNEY));
+  required boolean flag;
+}
+";
+        let schema = parse_message_type(message_type).unwrap();
+        assert_eq!(schema.to_string(), message_type);
+
+        let fields = schema.fields();
+        assert_eq!(
+            fields[1].fields()[0].fields()[0].to_string().trim(),
+            "optional binary element (STRING);"
+        );
+        let ParquetType::Primitive {
+            info,
+            type_length,
+            scale,
+            precision,
+            ..
+        } = &fields[2]
+        else {
+            panic!("expected a primitive type");
+        };
+        assert_eq!((*type_length, *precision, *scale), (16, 30, 2));
+        assert_eq!(info.converted_type, Some(ConvertedType::Decimal));
+
+        // Keywords are case insensitive and whitespace is free.
+        let compact = parse_message_type("MESSAGE m{OPTIONAL INT32 a(int_8)=3;}").unwrap();
+        assert_eq!(
+            compact.to_string(),
+            "message m {\n  optional int32 a (INT_8) = 3;\n}\n"
+        );
+
+        assert!(parse_message_type("message m { optional int32 a }").is_err());
+        assert!(parse_message_type("message m { optional int32 a (INTEGER(300,true)); }").is_err());
+        assert!(parse_message_type("message m { optional int32 a (INTEGER(12,true)); }").is_err());
+        assert!(parse_message_type("message m { optional group v (VARIANT(300)) {} }").is_err());
+        assert!(parse_message_type("message m { optional text a; }").is_err());
+        assert!(parse_message_type("message m { optional fixed_len_byte_array a; }").is_err());
+        assert!(parse_message_type("message m { optional fixed_len_byte_array(0) a; }").is_err());
+        assert!(parse_message_type("message m { optional fixed_len_byte_array(-3) a; }").is_err());
+        assert!(parse_message_type("message m { optional int32 a (DECIMAL(-5,0)); }").is_err());
+        assert!(parse_message_type("message m { optional int32 a (DECIMAL(0)); }").is_err());
+        assert!(parse_message_type("message m { optional int32 a (DECIMAL(5,6)); }").is_err());
+        assert!(parse_message_type("message m { optional int32 a (DECIMAL(5,-1)); }").is_err());
+    }
+}
@@ -1,7 +1,9 @@
+mod message_type;
 mod parse;
 mod statistics;
-mod thrift_defined;
+pub(crate) mod thrift_defined;
 pub mod types;
+mod version;
 
 use crate::errors::{ParquetError, ParquetResult};
 pub(crate) use crate::metadata::thrift_defined::*;
@@ -12,15 +14,27 @@ pub use statistics::*;
 use thrift::protocol::{TCompactInputProtocol, TSerializable};
 pub use thrift_defined::rosetta::*;
 
-pub use parse::get_metadata;
+pub use message_type::parse_message_type;
+pub use parse::{get_metadata, read_page_index};
+pub use version::ApplicationVersion;
+
+/// [`ColumnIndex`] of each column chunk, indexed by row group and then by column.
+/// `None` for column chunks of which the index was not read or not written.
+pub type ParquetColumnIndex = Vec<Vec<Option<ColumnIndex>>>;
+/// [`OffsetIndex`] of each column chunk, indexed by row group and then by column.
+/// `None` for column chunks of which the index was not read or not written.
+pub type ParquetOffsetIndex = Vec<Vec<Option<OffsetIndex>>>;
 
 /// Global Parquet metadata.
+///
+/// The metadata is `Send + Sync`, so that one parsed footer can be shared by the threads
+/// that decode its row groups and columns.
 #[derive(Debug, Clone)]
 pub struct ParquetMetaData {
     pub file_metadata: FileMetaData,
     pub row_groups: Vec<RowGroupMetaData>,
-    // Page index for all pages in each column chunk
-    // page_indexes: Option<ParquetColumnIndex>,
-    // Offset index for all pages in each column chunk
-    // offset_indexes: Option<ParquetOffsetIndex>,
+    /// Page index for all pages in each column chunk, see [`read_page_index`].
+    pub page_indexes: Option<ParquetColumnIndex>,
+    /// Offset index for all pages in each column chunk, see [`read_page_index`].
+    pub offset_indexes: Option<ParquetOffsetIndex>,
 }
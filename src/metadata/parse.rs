@@ -1,7 +1,7 @@
 use super::types::ColumnDescriptor;
 use super::*;
 use crate::metadata::types::{from_thrift, SchemaDescriptor};
-use std::rc::Rc;
+use std::sync::Arc;
 
 fn decode_footer(footer: &[u8]) -> ParquetResult<usize> {
     let magic_number_offset = FOOTER_SIZE - MAGIC_NUMBER.len();
@@ -55,48 +55,254 @@ fn decode_metadata(metadata: &[u8]) -> ParquetResult<ParquetMetaData> {
     let schema_tree = from_thrift(&t_file_metadata.schema)?;
     let schema_descr = SchemaDescriptor::new(schema_tree);
 
+    let column_orders = t_file_metadata
+        .column_orders
+        .as_ref()
+        .map(|column_orders| parse_column_orders(column_orders, &schema_descr))
+        .transpose()?;
+    let version = ApplicationVersion::parse(t_file_metadata.created_by.as_deref());
+
     let row_groups = t_file_metadata
         .row_groups
         .into_iter()
-        .map(|rg| RowGroupMetaData::from_thrift(&schema_descr, rg))
+        .map(|rg| {
+            RowGroupMetaData::from_thrift(&schema_descr, column_orders.as_deref(), &version, rg)
+        })
         .collect::<ParquetResult<Vec<_>>>()?;
-
-    let column_orders = t_file_metadata
-        .column_orders
-        .as_ref()
-        .map(|column_orders| parse_column_orders(column_orders, &schema_descr));
     let file_metadata = FileMetaData {
         version: t_file_metadata.version,
         num_rows: t_file_metadata.num_rows as _,
         created_by: t_file_metadata.created_by,
         key_value_metadata: t_file_metadata.key_value_metadata,
-        schema_descr: Rc::new(schema_descr),
+        schema_descr: Arc::new(schema_descr),
         column_orders,
     };
 
     Ok(ParquetMetaData {
         file_metadata,
         row_groups,
+        page_indexes: None,
+        offset_indexes: None,
     })
 }
 
-/// Parses column orders from Thrift definition.
+/// Reads the page index, the `ColumnIndex` and `OffsetIndex`, of the given `columns` in the
+/// given `row_groups`.
+///
+/// The result has an entry for every column chunk in the file. Entries of column chunks that
+/// were not requested or that have no page index are `None`. The result can be stored in
+/// [`ParquetMetaData::page_indexes`] and [`ParquetMetaData::offset_indexes`].
+///
+/// Fails if a row group or column is not in the file.
+pub fn read_page_index<R: ParquetReader>(
+    reader: R,
+    metadata: &ParquetMetaData,
+    row_groups: &[usize],
+    columns: &[usize],
+) -> ParquetResult<(ParquetColumnIndex, ParquetOffsetIndex)> {
+    let mut column_indexes: ParquetColumnIndex = metadata
+        .row_groups
+        .iter()
+        .map(|rg| vec![None; rg.columns.len()])
+        .collect();
+    let mut offset_indexes: ParquetOffsetIndex = metadata
+        .row_groups
+        .iter()
+        .map(|rg| vec![None; rg.columns.len()])
+        .collect();
+
+    let version = ApplicationVersion::parse(metadata.file_metadata.created_by.as_deref());
+    for &rg_i in row_groups {
+        let rg = metadata.row_groups.get(rg_i).ok_or_else(|| {
+            ParquetError::InvalidFormat(format!("The file has no row group {rg_i}"))
+        })?;
+        for &column_i in columns {
+            let column = rg.columns.get(column_i).ok_or_else(|| {
+                ParquetError::InvalidFormat(format!(
+                    "Row group {rg_i} has no column chunk {column_i}"
+                ))
+            })?;
+
+            if let (Some(offset), Some(length)) =
+                (column.column_index_offset(), column.column_index_length())
+            {
+                let bytes = reader.get_bytes(offset, length)?;
+                let mut protocol = TCompactInputProtocol::new(bytes);
+                let index = TColumnIndex::read_from_in_protocol(&mut protocol).map_err(|e| {
+                    ParquetError::InvalidFormat(format!("Could not parse column index: {}", e))
+                })?;
+                let column_order = metadata
+                    .file_metadata
+                    .column_orders
+                    .as_ref()
+                    .and_then(|orders| orders.get(column_i).copied())
+                    .unwrap_or(ColumnOrder::Undefined);
+                column_indexes[rg_i][column_i] = Some(ColumnIndex::from_thrift(
+                    column,
+                    column_order,
+                    &version,
+                    index,
+                )?);
+            }
+
+            if let (Some(offset), Some(length)) =
+                (column.offset_index_offset(), column.offset_index_length())
+            {
+                let bytes = reader.get_bytes(offset, length)?;
+                let mut protocol = TCompactInputProtocol::new(bytes);
+                let index = TOffsetIndex::read_from_in_protocol(&mut protocol).map_err(|e| {
+                    ParquetError::InvalidFormat(format!("Could not parse offset index: {}", e))
+                })?;
+                offset_indexes[rg_i][column_i] = Some(OffsetIndex::from_thrift(index)?);
+            }
+        }
+    }
+
+    Ok((column_indexes, offset_indexes))
+}
+
+/// Parses column orders from Thrift definition, one per leaf column.
+///
+/// Column orders unknown to this reader are [`ColumnOrder::Undefined`], so that the
+/// statistics of their columns are not trusted.
 fn parse_column_orders(
-    t_columns_orders: &[TColumnOrder],
+    t_columns_orders: &[Option<TColumnOrder>],
     schema_desc: &SchemaDescriptor,
-) -> Vec<ColumnOrder> {
-    debug_assert_eq!(schema_desc.leaves.len(), t_columns_orders.len());
+) -> ParquetResult<Vec<ColumnOrder>> {
+    if schema_desc.leaves.len() != t_columns_orders.len() {
+        return Err(ParquetError::InvalidFormat(format!(
+            "The file has {} column orders for {} leaf columns",
+            t_columns_orders.len(),
+            schema_desc.leaves.len()
+        )));
+    }
 
-    schema_desc
+    let column_orders = schema_desc
         .leaves
         .iter()
-        .map(|column| {
-            let sort_order = ColumnOrder::get_sort_order(
-                column.logical_type(),
-                column.converted_type(),
-                column.physical_type(),
-            );
-            ColumnOrder::TypeDefinedOrder(sort_order)
+        .zip(t_columns_orders)
+        .map(|(column, t_column_order)| match t_column_order {
+            Some(TColumnOrder::TYPEORDER(_)) => {
+                let sort_order = ColumnOrder::get_sort_order(
+                    column.logical_type(),
+                    column.converted_type(),
+                    column.physical_type(),
+                );
+                ColumnOrder::TypeDefinedOrder(sort_order)
+            }
+            None => ColumnOrder::Undefined,
         })
-        .collect()
+        .collect();
+    Ok(column_orders)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{write_file, TestColumn, TestValues};
+    use thrift::protocol::TCompactOutputProtocol;
+
+    #[test]
+    fn test_parse_column_orders() {
+        let file = write_file(vec![
+            TestColumn::new("a", false, TestValues::Int32(vec![vec![Some(1)]])),
+            TestColumn::new("b", false, TestValues::Int32(vec![vec![Some(2)]])),
+        ]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let schema_descr = &metadata.file_metadata.schema_descr;
+
+        let type_order = Some(TColumnOrder::TYPEORDER(Default::default()));
+        let column_orders = parse_column_orders(&[type_order.clone(), None], schema_descr);
+        assert_eq!(
+            column_orders.unwrap(),
+            vec![
+                ColumnOrder::TypeDefinedOrder(SortOrder::Signed),
+                ColumnOrder::Undefined
+            ]
+        );
+
+        let column_orders = parse_column_orders(&[type_order], schema_descr);
+        assert!(matches!(column_orders, Err(ParquetError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_row_group_columns() {
+        let file = write_file(vec![
+            TestColumn::new("a", false, TestValues::Int32(vec![vec![Some(1)]])),
+            TestColumn::new("b", false, TestValues::Int32(vec![vec![Some(2)]])),
+        ]);
+        let footer = decode_footer(&file[file.len() - FOOTER_SIZE..]).unwrap();
+        let metadata = &file[file.len() - FOOTER_SIZE - footer..];
+        let mut t_file_metadata =
+            ThriftFileMetaData::read_from_in_protocol(&mut TCompactInputProtocol::new(metadata))
+                .unwrap();
+        assert!(decode_metadata(metadata).is_ok());
+
+        // A row group without the column chunk of `b`.
+        t_file_metadata.row_groups[0].columns.pop();
+        let mut metadata = vec![];
+        t_file_metadata
+            .write_to_out_protocol(&mut TCompactOutputProtocol::new(&mut metadata))
+            .unwrap();
+        assert!(matches!(
+            decode_metadata(&metadata),
+            Err(ParquetError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_read_page_index() {
+        let pages = vec![
+            vec![Some(3), None, Some(1)],
+            vec![None, None],
+            vec![Some(-4), Some(8)],
+        ];
+        let file = write_file(vec![TestColumn::new("a", true, TestValues::Int32(pages))]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+
+        let (column_indexes, offset_indexes) =
+            read_page_index(file.as_slice(), &metadata, &[0], &[0]).unwrap();
+        let column_index = column_indexes[0][0].as_ref().unwrap();
+        let ColumnIndex::Int32(index) = column_index else {
+            panic!("expected an INT32 column index");
+        };
+        let bounds = index
+            .indexes
+            .iter()
+            .map(|page| (page.min, page.max, page.null_count, page.is_null_page()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bounds,
+            vec![
+                (Some(1), Some(3), Some(1), false),
+                (None, None, Some(2), true),
+                (Some(-4), Some(8), Some(0), false),
+            ]
+        );
+
+        let offset_index = offset_indexes[0][0].as_ref().unwrap();
+        let first_rows = offset_index
+            .page_locations
+            .iter()
+            .map(|location| location.first_row_index)
+            .collect::<Vec<_>>();
+        assert_eq!(first_rows, vec![0, 3, 5]);
+        let (start, length) = metadata.row_groups[0].columns[0].byte_range();
+        assert!(offset_index
+            .page_locations
+            .iter()
+            .all(|location| location.offset >= start
+                && location.offset + location.compressed_page_size <= start + length));
+
+        let out_of_range = read_page_index(file.as_slice(), &metadata, &[1], &[0]);
+        assert!(matches!(out_of_range, Err(ParquetError::InvalidFormat(_))));
+        let out_of_range = read_page_index(file.as_slice(), &metadata, &[0], &[1]);
+        assert!(matches!(out_of_range, Err(ParquetError::InvalidFormat(_))));
+    }
+
+    #[test]
+    fn test_metadata_is_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ParquetMetaData>();
+    }
 }
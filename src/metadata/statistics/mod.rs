@@ -1,18 +1,19 @@
 use crate::errors::{ParquetError, ParquetResult};
-use crate::metadata::{PhysicalType, TStatistic};
-use crate::physical::NativeType;
+use crate::metadata::{ApplicationVersion, ColumnOrder, PhysicalType, SortOrder, TStatistic};
+use crate::physical::{f16_to_f32, ByteArray, FixedLenByteArray, Int96, NativeType, ParquetOrd};
+use std::cmp::Ordering;
+use std::ops::Neg;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Statistics {
     Boolean(ValueStatistics<bool>),
     Int32(ValueStatistics<i32>),
     Int64(ValueStatistics<i64>),
+    Int96(ValueStatistics<Int96>),
     Float(ValueStatistics<f32>),
     Double(ValueStatistics<f64>),
-    // Maybe support these later
-    // Int96(ValueStatistics<i128>),
-    // ByteArray(ValueStatistics<ByteArray>),
-    // FixedLenByteArray(ValueStatistics<FixedLenByteArray>),
+    ByteArray(ValueStatistics<ByteArray>),
+    FixedLenByteArray(ValueStatistics<FixedLenByteArray>),
 }
 
 /// Statistics for a particular `ParquetValueType`
@@ -22,25 +23,252 @@ pub struct ValueStatistics<T> {
     max: Option<T>,
     // Distinct count could be omitted in some cases
     distinct_count: Option<usize>,
-    null_count: usize,
+    // `None` if the writer did not write it, which does not mean there are no nulls
+    null_count: Option<usize>,
+    // Only written for floating point columns, `None` otherwise
+    nan_count: Option<usize>,
 
     /// If `true` populate the deprecated `min` and `max` fields instead of
     /// `min_value` and `max_value`
     is_min_max_deprecated: bool,
+
+    /// If `false` the writer may have truncated the values, `min` is then only a lower
+    /// bound and `max` only an upper bound of the actual values.
+    is_min_value_exact: bool,
+    is_max_value_exact: bool,
 }
 
-fn decode_primitive<T: NativeType>(data: &[u8]) -> T {
-    T::from_le_bytes(data[..std::mem::size_of::<T>()].try_into().unwrap())
+impl<T> ValueStatistics<T> {
+    /// Statistics of which `min` and `max` are bounds that may not be exact.
+    pub(crate) fn new_inexact(min: Option<T>, max: Option<T>, null_count: Option<usize>) -> Self {
+        ValueStatistics {
+            min,
+            max,
+            distinct_count: None,
+            null_count,
+            nan_count: None,
+            is_min_max_deprecated: false,
+            is_min_value_exact: false,
+            is_max_value_exact: false,
+        }
+    }
+
+    /// Sets the number of NaN values.
+    pub(crate) fn with_nan_count(mut self, nan_count: Option<usize>) -> Self {
+        self.nan_count = nan_count;
+        self
+    }
+
+    /// Returns the minimum value, if it was written.
+    pub fn min(&self) -> Option<&T> {
+        self.min.as_ref()
+    }
+
+    /// Returns the maximum value, if it was written.
+    pub fn max(&self) -> Option<&T> {
+        self.max.as_ref()
+    }
+
+    /// Returns the number of distinct values, if it was written.
+    pub fn distinct_count(&self) -> Option<usize> {
+        self.distinct_count
+    }
+
+    /// Returns the number of nulls, if it was written.
+    pub fn null_count(&self) -> Option<usize> {
+        self.null_count
+    }
+
+    /// Returns the number of NaN values of a floating point column, if it was written.
+    pub fn nan_count(&self) -> Option<usize> {
+        self.nan_count
+    }
+
+    /// Whether `min` and `max` were read from the deprecated `min`/`max` fields.
+    pub fn is_min_max_deprecated(&self) -> bool {
+        self.is_min_max_deprecated
+    }
+
+    /// Whether `min` is the actual minimum. If not, it is a lower bound.
+    pub fn is_min_value_exact(&self) -> bool {
+        self.is_min_value_exact
+    }
+
+    /// Whether `max` is the actual maximum. If not, it is an upper bound.
+    pub fn is_max_value_exact(&self) -> bool {
+        self.is_max_value_exact
+    }
 }
 
-impl Statistics {
-    pub fn from_thrift(
-        physical_type: PhysicalType,
-        statistics: TStatistic,
-    ) -> ParquetResult<Option<Statistics>> {
-        let null_count: usize = statistics.null_count.unwrap_or(0).try_into().map_err(|_| {
-            ParquetError::InvalidFormat("Negative 'null_count' found in statistics".into())
-        })?;
+impl<T: ParquetOrd + Clone> ValueStatistics<T> {
+    /// Merges the statistics of `other`, e.g. of another page, row group or file, into
+    /// `self`.
+    ///
+    /// `min` and `max` become unknown if they are unknown on either side, or cannot be
+    /// compared with `sort_order`, e.g. because one is NaN. Statistics without any value,
+    /// which have no `min` and `max`, should therefore not be merged for them.
+    /// `distinct_count` becomes unknown, as values may be in both, unless either side has
+    /// no distinct values.
+    pub fn merge(&mut self, other: &ValueStatistics<T>, sort_order: SortOrder) {
+        let (min, min_exact) = merge_bound(
+            (self.min.take(), self.is_min_value_exact),
+            (other.min.as_ref(), other.is_min_value_exact),
+            |left, right| left.compare(right, sort_order),
+        );
+        let (max, max_exact) = merge_bound(
+            (self.max.take(), self.is_max_value_exact),
+            (other.max.as_ref(), other.is_max_value_exact),
+            |left, right| right.compare(left, sort_order),
+        );
+        self.min = min;
+        self.max = max;
+        self.is_min_value_exact = min_exact;
+        self.is_max_value_exact = max_exact;
+        self.distinct_count = match (self.distinct_count, other.distinct_count) {
+            (Some(0), count) | (count, Some(0)) => count,
+            _ => None,
+        };
+        self.null_count = self.null_count.zip(other.null_count).map(|(l, r)| l + r);
+        self.nan_count = self.nan_count.zip(other.nan_count).map(|(l, r)| l + r);
+        self.is_min_max_deprecated |= other.is_min_max_deprecated;
+    }
+}
+
+/// Returns the least of two bounds according to `cmp`, and whether it is exact. It is
+/// exact if a side of which the bound is exact attains it.
+fn merge_bound<T: Clone>(
+    (left, left_exact): (Option<T>, bool),
+    (right, right_exact): (Option<&T>, bool),
+    cmp: impl Fn(&T, &T) -> Option<Ordering>,
+) -> (Option<T>, bool) {
+    let (Some(left), Some(right)) = (left, right) else {
+        return (None, false);
+    };
+    match cmp(&left, right) {
+        Some(Ordering::Less) => (Some(left), left_exact),
+        Some(Ordering::Equal) => (Some(left), left_exact || right_exact),
+        Some(Ordering::Greater) => (Some(right.clone()), right_exact),
+        None => (None, false),
+    }
+}
+
+/// Applies the rules of the specification to the `min` and `max` of floating point
+/// values. Writers may or may not have included NaN in them, so a NaN bound makes both
+/// unknown. Writers may not have distinguished `-0.0` from `+0.0`, so a `+0.0` min
+/// becomes `-0.0` and a `-0.0` max becomes `+0.0`.
+///
+/// Returns whether the sign of `min` and of `max` was changed.
+pub(crate) fn normalize_float_bounds<T>(min: &mut Option<T>, max: &mut Option<T>) -> (bool, bool)
+where
+    T: Copy + Into<f64> + Neg<Output = T>,
+{
+    let is_nan = |value: &Option<T>| value.is_some_and(|v| v.into().is_nan());
+    if is_nan(min) || is_nan(max) {
+        *min = None;
+        *max = None;
+        return (false, false);
+    }
+    let is_zero = |value: T, positive: bool| {
+        let value: f64 = value.into();
+        value == 0.0 && value.is_sign_positive() == positive
+    };
+    let flip = |bound: &mut Option<T>, positive: bool| match bound {
+        Some(v) if is_zero(*v, positive) => {
+            *v = -*v;
+            true
+        }
+        _ => false,
+    };
+    (flip(min, true), flip(max, false))
+}
+
+impl<T: Copy + Into<f64> + Neg<Output = T>> ValueStatistics<T> {
+    /// Applies [`normalize_float_bounds`]. A bound of which the sign was changed is no
+    /// longer a value of the column, so it is not exact.
+    fn normalize_float_bounds(&mut self) {
+        let (min_changed, max_changed) = normalize_float_bounds(&mut self.min, &mut self.max);
+        self.is_min_value_exact &= !min_changed;
+        self.is_max_value_exact &= !max_changed;
+    }
+}
+
+/// Calls `$f` with the [`ValueStatistics`] of any variant of [`Statistics`].
+macro_rules! with_value_statistics {
+    ($stats:expr, $s:ident => $f:expr) => {
+        match $stats {
+            Statistics::Boolean($s) => $f,
+            Statistics::Int32($s) => $f,
+            Statistics::Int64($s) => $f,
+            Statistics::Int96($s) => $f,
+            Statistics::Float($s) => $f,
+            Statistics::Double($s) => $f,
+            Statistics::ByteArray($s) => $f,
+            Statistics::FixedLenByteArray($s) => $f,
+        }
+    };
+}
+
+pub(crate) fn decode_primitive<T: NativeType>(data: &[u8]) -> ParquetResult<T> {
+    let bytes = data.try_into().map_err(|_| {
+        ParquetError::InvalidFormat(format!(
+            "Expected {} bytes for a statistics value, found {}",
+            std::mem::size_of::<T>(),
+            data.len()
+        ))
+    })?;
+    Ok(T::from_le_bytes(bytes))
+}
+
+pub(crate) fn decode_bool(data: &[u8]) -> ParquetResult<bool> {
+    match data.first() {
+        Some(v) => Ok(*v != 0),
+        None => Err(ParquetError::InvalidFormat(
+            "Expected 1 byte for a boolean statistics value, found 0".into(),
+        )),
+    }
+}
+
+pub(crate) fn decode_int96(data: &[u8]) -> ParquetResult<Int96> {
+    let bytes = data.try_into().map_err(|_| {
+        ParquetError::InvalidFormat(format!(
+            "Expected 12 bytes for an INT96 statistics value, found {}",
+            data.len()
+        ))
+    })?;
+    Ok(Int96::from_le_bytes(bytes))
+}
+
+pub(crate) fn decode_float16(data: &[u8]) -> ParquetResult<f32> {
+    let bytes = data.try_into().map_err(|_| {
+        ParquetError::InvalidFormat(format!(
+            "Expected 2 bytes for a FLOAT16 statistics value, found {}",
+            data.len()
+        ))
+    })?;
+    Ok(f16_to_f32(u16::from_le_bytes(bytes)))
+}
+
+/// The statistics fields shared by all physical types, before `min` and `max` are decoded.
+struct EncodedStatistics {
+    min: Option<Vec<u8>>,
+    max: Option<Vec<u8>>,
+    distinct_count: Option<usize>,
+    null_count: Option<usize>,
+    nan_count: Option<usize>,
+    is_min_max_deprecated: bool,
+    is_min_value_exact: Option<bool>,
+    is_max_value_exact: Option<bool>,
+}
+
+impl EncodedStatistics {
+    fn from_thrift(statistics: TStatistic) -> ParquetResult<Self> {
+        let null_count: Option<usize> = statistics
+            .null_count
+            .map(|v| v.try_into())
+            .transpose()
+            .map_err(|_| {
+                ParquetError::InvalidFormat("Negative 'null_count' found in statistics".into())
+            })?;
         let distinct_count: Option<usize> = statistics
             .distinct_count
             .map(|v| v.try_into())
@@ -48,6 +276,13 @@ impl Statistics {
             .map_err(|_| {
                 ParquetError::InvalidFormat("Negative 'distinct_count' found in statistics".into())
             })?;
+        let nan_count: Option<usize> = statistics
+            .nan_count
+            .map(|v| v.try_into())
+            .transpose()
+            .map_err(|_| {
+                ParquetError::InvalidFormat("Negative 'nan_count' found in statistics".into())
+            })?;
 
         // Whether statistics use deprecated min/max fields
         let old_format = statistics.min_value.is_none() && statistics.max_value.is_none();
@@ -58,69 +293,267 @@ impl Statistics {
             (statistics.min_value, statistics.max_value)
         };
 
-        let out = match physical_type {
-            PhysicalType::Double => {
-                let min = min_encoded.map(|data| decode_primitive::<f64>(&data));
-                let max = max_encoded.map(|data| decode_primitive::<f64>(&data));
-
-                Statistics::Double(ValueStatistics {
-                    min,
-                    max,
-                    null_count,
-                    distinct_count,
-                    is_min_max_deprecated: old_format,
-                })
+        Ok(EncodedStatistics {
+            min: min_encoded,
+            max: max_encoded,
+            distinct_count,
+            null_count,
+            nan_count,
+            is_min_max_deprecated: old_format,
+            is_min_value_exact: statistics.is_min_value_exact,
+            is_max_value_exact: statistics.is_max_value_exact,
+        })
+    }
+
+    /// Decode `min` and `max` with `decode`.
+    ///
+    /// Fixed size values cannot be truncated by writers, so these are exact unless the
+    /// writer says otherwise. Byte arrays are only exact when the writer says so.
+    fn decode<T>(
+        self,
+        decode: impl Fn(&[u8]) -> ParquetResult<T>,
+        exact_by_default: bool,
+    ) -> ParquetResult<ValueStatistics<T>> {
+        Ok(ValueStatistics {
+            min: self.min.as_deref().map(&decode).transpose()?,
+            max: self.max.as_deref().map(&decode).transpose()?,
+            distinct_count: self.distinct_count,
+            null_count: self.null_count,
+            nan_count: self.nan_count,
+            is_min_max_deprecated: self.is_min_max_deprecated,
+            is_min_value_exact: self.is_min_value_exact.unwrap_or(exact_by_default),
+            is_max_value_exact: self.is_max_value_exact.unwrap_or(exact_by_default),
+        })
+    }
+}
+
+impl Statistics {
+    /// Returns the number of nulls in the column chunk or page, if it was written.
+    pub fn null_count(&self) -> Option<usize> {
+        with_value_statistics!(self, s => s.null_count())
+    }
+
+    /// Returns the number of NaN values of a floating point column chunk or page, if it
+    /// was written.
+    pub fn nan_count(&self) -> Option<usize> {
+        with_value_statistics!(self, s => s.nan_count())
+    }
+
+    /// Returns the number of distinct values, if it was written.
+    pub fn distinct_count(&self) -> Option<usize> {
+        with_value_statistics!(self, s => s.distinct_count())
+    }
+
+    /// Whether `min` and `max` were read from the deprecated `min`/`max` fields.
+    pub fn is_min_max_deprecated(&self) -> bool {
+        with_value_statistics!(self, s => s.is_min_max_deprecated())
+    }
+
+    /// Merges `other` into `self`, see [`ValueStatistics::merge`]. Fails if the physical
+    /// types differ.
+    pub fn merge(&mut self, other: &Statistics, sort_order: SortOrder) -> ParquetResult<()> {
+        match (self, other) {
+            (Statistics::Boolean(s), Statistics::Boolean(o)) => s.merge(o, sort_order),
+            (Statistics::Int32(s), Statistics::Int32(o)) => s.merge(o, sort_order),
+            (Statistics::Int64(s), Statistics::Int64(o)) => s.merge(o, sort_order),
+            (Statistics::Int96(s), Statistics::Int96(o)) => s.merge(o, sort_order),
+            (Statistics::Float(s), Statistics::Float(o)) => s.merge(o, sort_order),
+            (Statistics::Double(s), Statistics::Double(o)) => s.merge(o, sort_order),
+            (Statistics::ByteArray(s), Statistics::ByteArray(o)) => s.merge(o, sort_order),
+            (Statistics::FixedLenByteArray(s), Statistics::FixedLenByteArray(o)) => {
+                s.merge(o, sort_order)
             }
-            PhysicalType::Float => {
-                let min = min_encoded.map(|data| decode_primitive::<f32>(&data));
-                let max = max_encoded.map(|data| decode_primitive::<f32>(&data));
-
-                Statistics::Float(ValueStatistics {
-                    min,
-                    max,
-                    null_count,
-                    distinct_count,
-                    is_min_max_deprecated: old_format,
-                })
+            (s, o) => {
+                return Err(ParquetError::InvalidFormat(format!(
+                    "Cannot merge {:?} statistics with {:?} statistics",
+                    s.physical_type(),
+                    o.physical_type()
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Adds the nulls of statistics without values, which are not merged for their
+    /// missing `min` and `max`.
+    pub(crate) fn add_null_count(&mut self, null_count: Option<usize>) {
+        with_value_statistics!(self, s => {
+            s.null_count = s.null_count.zip(null_count).map(|(l, r)| l + r)
+        })
+    }
+
+    /// Returns the physical type of the statistics values.
+    pub fn physical_type(&self) -> PhysicalType {
+        match self {
+            Statistics::Boolean(_) => PhysicalType::Boolean,
+            Statistics::Int32(_) => PhysicalType::Int32,
+            Statistics::Int64(_) => PhysicalType::Int64,
+            Statistics::Int96(_) => PhysicalType::Int96,
+            Statistics::Float(_) => PhysicalType::Float,
+            Statistics::Double(_) => PhysicalType::Double,
+            Statistics::ByteArray(_) => PhysicalType::ByteArray,
+            Statistics::FixedLenByteArray(_) => PhysicalType::FixedLenByteArray,
+        }
+    }
+
+    /// Drops `min` and `max` if they cannot be trusted.
+    ///
+    /// Before parquet-format 2.4.0 (no column order, only the deprecated `min`/`max`
+    /// fields) min and max were aggregated with signed comparison for every type.
+    /// Those are wrong for columns with an unsigned sort order, such as UTF8 strings or
+    /// unsigned integers, unless min equals max
+    /// ([PARQUET-686](https://issues.apache.org/jira/browse/PARQUET-686)).
+    /// The order of the `min_value`/`max_value` fields is only known from the column
+    /// order, so they are dropped if it is [`ColumnOrder::Undefined`], i.e. missing or
+    /// unknown to this reader.
+    /// Statistics of writers with known bugs are dropped as well,
+    /// see [`ApplicationVersion::has_correct_statistics`].
+    pub(crate) fn drop_untrusted_min_max(
+        &mut self,
+        column_order: ColumnOrder,
+        sort_order: SortOrder,
+        version: &ApplicationVersion,
+    ) {
+        let physical_type = self.physical_type();
+        let deprecated = self.is_min_max_deprecated();
+        with_value_statistics!(self, s => {
+            let min_equals_max = s.min.is_some() && s.min == s.max;
+            let order_known = match column_order {
+                _ if deprecated => sort_order.is_signed() || min_equals_max,
+                ColumnOrder::TypeDefinedOrder(_) => true,
+                ColumnOrder::Undefined => false,
+            };
+            let trusted = order_known
+                && version.has_correct_statistics(physical_type, sort_order, min_equals_max);
+            if !trusted {
+                s.min = None;
+                s.max = None;
             }
+        })
+    }
+
+    pub fn from_thrift(
+        physical_type: PhysicalType,
+        statistics: TStatistic,
+    ) -> ParquetResult<Option<Statistics>> {
+        let encoded = EncodedStatistics::from_thrift(statistics)?;
+        let out = match physical_type {
+            PhysicalType::Boolean => Statistics::Boolean(encoded.decode(decode_bool, true)?),
             PhysicalType::Int32 => {
-                let min = min_encoded.map(|data| decode_primitive::<i32>(&data));
-                let max = max_encoded.map(|data| decode_primitive::<i32>(&data));
-
-                Statistics::Int32(ValueStatistics {
-                    min,
-                    max,
-                    null_count,
-                    distinct_count,
-                    is_min_max_deprecated: old_format,
-                })
+                Statistics::Int32(encoded.decode(decode_primitive::<i32>, true)?)
             }
             PhysicalType::Int64 => {
-                let min = min_encoded.map(|data| decode_primitive::<i64>(&data));
-                let max = max_encoded.map(|data| decode_primitive::<i64>(&data));
-
-                Statistics::Int64(ValueStatistics {
-                    min,
-                    max,
-                    null_count,
-                    distinct_count,
-                    is_min_max_deprecated: old_format,
-                })
+                Statistics::Int64(encoded.decode(decode_primitive::<i64>, true)?)
+            }
+            PhysicalType::Int96 => Statistics::Int96(encoded.decode(decode_int96, true)?),
+            PhysicalType::Float => {
+                let mut s = encoded.decode(decode_primitive::<f32>, true)?;
+                s.normalize_float_bounds();
+                Statistics::Float(s)
             }
-            PhysicalType::Boolean => {
-                let min = min_encoded.map(|data| data[0] != 0);
-                let max = max_encoded.map(|data| data[0] != 0);
-
-                Statistics::Boolean(ValueStatistics {
-                    min,
-                    max,
-                    null_count,
-                    distinct_count,
-                    is_min_max_deprecated: old_format,
-                })
+            PhysicalType::Double => {
+                let mut s = encoded.decode(decode_primitive::<f64>, true)?;
+                s.normalize_float_bounds();
+                Statistics::Double(s)
             }
-            _ => return Ok(None),
+            // Byte arrays are stored without the length prefix of the PLAIN encoding.
+            PhysicalType::ByteArray => Statistics::ByteArray(
+                encoded.decode(|data| Ok(ByteArray::new(data.to_vec())), false)?,
+            ),
+            PhysicalType::FixedLenByteArray => Statistics::FixedLenByteArray(
+                encoded.decode(|data| Ok(FixedLenByteArray::new(data.to_vec())), false)?,
+            ),
         };
         Ok(Some(out))
     }
+
+    /// Decodes the statistics of a column of the FLOAT16 logical type to `f32`, as the
+    /// bytes of its little endian values cannot be compared.
+    pub(crate) fn from_thrift_float16(statistics: TStatistic) -> ParquetResult<Statistics> {
+        let mut s = EncodedStatistics::from_thrift(statistics)?.decode(decode_float16, true)?;
+        s.normalize_float_bounds();
+        Ok(Statistics::Float(s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() {
+        let mut stats = ValueStatistics::new_inexact(Some(3), Some(10), Some(1));
+        stats.is_max_value_exact = true;
+        let mut other = ValueStatistics::new_inexact(Some(-1), Some(10), Some(2));
+        other.is_min_value_exact = true;
+        other.distinct_count = Some(5);
+
+        stats.merge(&other, SortOrder::Signed);
+        assert_eq!((stats.min(), stats.max()), (Some(&-1), Some(&10)));
+        assert!(stats.is_min_value_exact() && stats.is_max_value_exact());
+        assert_eq!(stats.null_count(), Some(3));
+        assert_eq!(stats.distinct_count(), None);
+
+        // -1 is the largest value as unsigned
+        let negative = ValueStatistics::new_inexact(Some(-1), Some(-1), Some(0));
+        stats.merge(&negative, SortOrder::Unsigned);
+        assert_eq!(stats.max(), Some(&-1));
+
+        let mut floats = ValueStatistics::new_inexact(Some(1.0), Some(2.0), None);
+        floats.merge(
+            &ValueStatistics::new_inexact(Some(f64::NAN), Some(3.0), Some(0)),
+            SortOrder::Signed,
+        );
+        assert_eq!((floats.min(), floats.max()), (None, Some(&3.0)));
+        assert_eq!(floats.null_count(), None);
+
+        let mut statistics = Statistics::Int32(stats);
+        assert!(statistics
+            .merge(&Statistics::Double(floats), SortOrder::Signed)
+            .is_err());
+    }
+
+    #[test]
+    fn test_drop_untrusted_min_max() {
+        let version = ApplicationVersion::parse(Some("parquet-mr version 1.12.3"));
+        let drop = |column_order, deprecated| {
+            let mut stats = ValueStatistics::new_inexact(Some(-3), Some(5), Some(0));
+            stats.is_min_max_deprecated = deprecated;
+            let mut statistics = Statistics::Int32(stats);
+            statistics.drop_untrusted_min_max(column_order, SortOrder::Signed, &version);
+            let Statistics::Int32(stats) = statistics else {
+                unreachable!()
+            };
+            (stats.min().copied(), stats.max().copied())
+        };
+        let type_defined = ColumnOrder::TypeDefinedOrder(SortOrder::Signed);
+        assert_eq!(drop(type_defined, false), (Some(-3), Some(5)));
+        assert_eq!(drop(ColumnOrder::Undefined, false), (None, None));
+        // The deprecated fields are signed regardless of the column order.
+        assert_eq!(drop(ColumnOrder::Undefined, true), (Some(-3), Some(5)));
+    }
+
+    #[test]
+    fn test_float_bounds() {
+        let thrift = |min: f64, max: f64| TStatistic {
+            min_value: Some(min.to_le_bytes().to_vec()),
+            max_value: Some(max.to_le_bytes().to_vec()),
+            nan_count: Some(1),
+            ..Default::default()
+        };
+        let Some(Statistics::Double(stats)) =
+            Statistics::from_thrift(PhysicalType::Double, thrift(0.0, -0.0)).unwrap()
+        else {
+            panic!("expected double statistics");
+        };
+        assert!(stats.min().unwrap().is_sign_negative());
+        assert!(stats.max().unwrap().is_sign_positive());
+        assert_eq!(stats.nan_count(), Some(1));
+
+        let stats = Statistics::from_thrift(PhysicalType::Double, thrift(1.0, f64::NAN)).unwrap();
+        let Some(Statistics::Double(stats)) = stats else {
+            panic!("expected double statistics");
+        };
+        assert_eq!((stats.min(), stats.max()), (None, None));
+    }
 }
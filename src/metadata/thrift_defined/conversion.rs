@@ -29,7 +29,7 @@ impl From<parquet_format::ConvertedType> for rosetta::ConvertedType {
             parquet_format::ConvertedType::JSON => Json,
             parquet_format::ConvertedType::BSON => Bson,
             parquet_format::ConvertedType::INTERVAL => Interval,
-            _ => todo!(),
+            other => Unknown(other.0),
         }
     }
 }
@@ -63,6 +63,17 @@ impl From<parquet_format::LogicalType> for rosetta::LogicalType {
             parquet_format::LogicalType::JSON(_) => Json,
             parquet_format::LogicalType::BSON(_) => Bson,
             parquet_format::LogicalType::UUID(_) => Uuid,
+            parquet_format::LogicalType::FLOAT16(_) => Float16,
+            parquet_format::LogicalType::VARIANT(t) => Variant {
+                specification_version: t.specification_version,
+            },
+            parquet_format::LogicalType::GEOMETRY(t) => Geometry { crs: t.crs },
+            parquet_format::LogicalType::GEOGRAPHY(t) => Geography {
+                crs: t.crs,
+                algorithm: t
+                    .algorithm
+                    .unwrap_or(parquet_format::EdgeInterpolationAlgorithm::SPHERICAL),
+            },
         }
     }
 }
@@ -99,10 +110,10 @@ impl TryFrom<parquet_format::Type> for rosetta::PhysicalType {
             4 => Float,
             5 => Double,
             6 => ByteArray,
-            8 => FixedLenByteArray,
+            7 => FixedLenByteArray,
             _ => {
                 return Err(ParquetError::InvalidFormat(
-                    "Type value should be between 0-8.".into(),
+                    "Type value should be between 0-7.".into(),
                 ))
             }
         };
@@ -111,12 +122,10 @@ impl TryFrom<parquet_format::Type> for rosetta::PhysicalType {
     }
 }
 
-impl TryFrom<parquet_format::CompressionCodec> for rosetta::Compression {
-    type Error = ParquetError;
-
-    fn try_from(value: parquet_format::CompressionCodec) -> Result<Self, Self::Error> {
+impl From<parquet_format::CompressionCodec> for rosetta::Compression {
+    fn from(value: parquet_format::CompressionCodec) -> Self {
         use rosetta::Compression::*;
-        let out = match value.0 {
+        match value.0 {
             0 => Uncompressed,
             1 => Snappy,
             2 => Gzip,
@@ -125,23 +134,15 @@ impl TryFrom<parquet_format::CompressionCodec> for rosetta::Compression {
             5 => Lz4,
             6 => Zstd,
             7 => Lz4Raw,
-            _ => {
-                return Err(ParquetError::InvalidFormat(
-                    "PageType value should be between 0-3.".into(),
-                ))
-            }
-        };
-
-        Ok(out)
+            other => Unknown(other),
+        }
     }
 }
 
-impl TryFrom<parquet_format::Encoding> for rosetta::Encoding {
-    type Error = ParquetError;
-
-    fn try_from(value: parquet_format::Encoding) -> Result<Self, Self::Error> {
+impl From<parquet_format::Encoding> for rosetta::Encoding {
+    fn from(value: parquet_format::Encoding) -> Self {
         use rosetta::Encoding::*;
-        let out = match value.0 {
+        match value.0 {
             0 => Plain,
             2 => PlainDictionary,
             3 => RLE,
@@ -151,17 +152,133 @@ impl TryFrom<parquet_format::Encoding> for rosetta::Encoding {
             7 => DeltaByteArray,
             8 => RLE_Dictionary,
             9 => ByteStreamSplit,
-            _ => return Err(ParquetError::InvalidFormat("Invalid encoding.".into())),
+            other => Unknown(other),
+        }
+    }
+}
+
+impl TryFrom<parquet_format::BoundaryOrder> for rosetta::BoundaryOrder {
+    type Error = ParquetError;
+
+    fn try_from(value: parquet_format::BoundaryOrder) -> Result<Self, Self::Error> {
+        use rosetta::BoundaryOrder::*;
+        let out = match value.0 {
+            0 => Unordered,
+            1 => Ascending,
+            2 => Descending,
+            _ => {
+                return Err(ParquetError::InvalidFormat(
+                    "BoundaryOrder value should be between 0-2.".into(),
+                ))
+            }
         };
 
         Ok(out)
     }
 }
 
+impl TryFrom<parquet_format::PageEncodingStats> for rosetta::PageEncodingStats {
+    type Error = ParquetError;
+
+    fn try_from(value: parquet_format::PageEncodingStats) -> Result<Self, Self::Error> {
+        Ok(rosetta::PageEncodingStats {
+            page_type: value.page_type.into(),
+            encoding: value.encoding.into(),
+            count: value.count.try_into().map_err(|_| {
+                ParquetError::InvalidFormat("Negative page count in encoding stats".into())
+            })?,
+        })
+    }
+}
+
+impl TryFrom<parquet_format::SizeStatistics> for rosetta::SizeStatistics {
+    type Error = ParquetError;
+
+    fn try_from(value: parquet_format::SizeStatistics) -> Result<Self, Self::Error> {
+        let invalid = || ParquetError::InvalidFormat("Negative value in size statistics".into());
+        let histogram = |histogram: Option<Vec<i64>>| {
+            histogram
+                .map(|histogram| {
+                    histogram
+                        .into_iter()
+                        .map(|count| count.try_into().map_err(|_| invalid()))
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()
+        };
+        Ok(rosetta::SizeStatistics {
+            unencoded_byte_array_data_bytes: value
+                .unencoded_byte_array_data_bytes
+                .map(|bytes| bytes.try_into().map_err(|_| invalid()))
+                .transpose()?,
+            repetition_level_histogram: histogram(value.repetition_level_histogram)?,
+            definition_level_histogram: histogram(value.definition_level_histogram)?,
+        })
+    }
+}
+
+impl From<parquet_format::BoundingBox> for rosetta::BoundingBox {
+    fn from(value: parquet_format::BoundingBox) -> Self {
+        rosetta::BoundingBox {
+            xmin: value.xmin.into(),
+            xmax: value.xmax.into(),
+            ymin: value.ymin.into(),
+            ymax: value.ymax.into(),
+            zmin: value.zmin.map(|v| v.into()),
+            zmax: value.zmax.map(|v| v.into()),
+            mmin: value.mmin.map(|v| v.into()),
+            mmax: value.mmax.map(|v| v.into()),
+        }
+    }
+}
+
+impl From<parquet_format::GeospatialStatistics> for rosetta::GeospatialStatistics {
+    fn from(value: parquet_format::GeospatialStatistics) -> Self {
+        rosetta::GeospatialStatistics {
+            bbox: value.bbox.map(|bbox| bbox.into()),
+            // An empty list means the types are not known.
+            geospatial_types: value.geospatial_types.filter(|types| !types.is_empty()),
+        }
+    }
+}
+
 impl From<parquet_format::PageType> for rosetta::PageType {
     fn from(value: parquet_format::PageType) -> Self {
-        let variant = value.0 as u8;
-        assert!(variant < 4);
-        unsafe { std::mem::transmute(variant) }
+        use rosetta::PageType::*;
+        match value.0 {
+            0 => DataPageV1,
+            1 => IndexPage,
+            2 => DictionaryPage,
+            3 => DataPageV2,
+            other => Unknown(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_physical_type() {
+        let physical_types = parquet_format::Type::ENUM_VALUES
+            .iter()
+            .map(|tp| rosetta::PhysicalType::try_from(*tp).unwrap())
+            .collect::<Vec<_>>();
+        use rosetta::PhysicalType::*;
+        assert_eq!(
+            physical_types,
+            vec![
+                Boolean,
+                Int32,
+                Int64,
+                Int96,
+                Float,
+                Double,
+                ByteArray,
+                FixedLenByteArray
+            ]
+        );
+        assert!(rosetta::PhysicalType::try_from(parquet_format::Type(8)).is_err());
     }
 }
@@ -3,13 +3,15 @@
 // and: https://github.com/apache/parquet-format/blob/master/src/main/thrift/parquet.thrift
 // for the format specification
 mod conversion;
-mod parquet_format;
+pub(crate) mod parquet_format;
 pub(crate) mod rosetta;
 
 pub use rosetta::*;
 
 pub(crate) use parquet_format::{
-    ColumnOrder as TColumnOrder, FileMetaData as ThriftFileMetaData, SchemaElement,
+    BloomFilterHeader as TBloomFilterHeader, ColumnIndex as TColumnIndex,
+    ColumnOrder as TColumnOrder, FileMetaData as ThriftFileMetaData, OffsetIndex as TOffsetIndex,
+    SchemaElement,
 };
 /// These autogen seem fine for now.
 pub(crate) use parquet_format::{
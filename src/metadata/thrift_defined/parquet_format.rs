@@ -631,6 +631,432 @@ impl From<&BoundaryOrder> for i32 {
     }
 }
 
+/// Interpolation algorithm of the edges of a GEOGRAPHY, i.e. how to connect two
+/// vertices on the sphere.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct EdgeInterpolationAlgorithm(pub i32);
+
+impl EdgeInterpolationAlgorithm {
+    pub const SPHERICAL: EdgeInterpolationAlgorithm = EdgeInterpolationAlgorithm(0);
+    pub const VINCENTY: EdgeInterpolationAlgorithm = EdgeInterpolationAlgorithm(1);
+    pub const THOMAS: EdgeInterpolationAlgorithm = EdgeInterpolationAlgorithm(2);
+    pub const ANDOYER: EdgeInterpolationAlgorithm = EdgeInterpolationAlgorithm(3);
+    pub const KARNEY: EdgeInterpolationAlgorithm = EdgeInterpolationAlgorithm(4);
+    pub const ENUM_VALUES: &'static [Self] = &[
+        Self::SPHERICAL,
+        Self::VINCENTY,
+        Self::THOMAS,
+        Self::ANDOYER,
+        Self::KARNEY,
+    ];
+}
+
+impl TSerializable for EdgeInterpolationAlgorithm {
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+        o_prot.write_i32(self.0)
+    }
+    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<EdgeInterpolationAlgorithm> {
+        let enum_value = i_prot.read_i32()?;
+        Ok(EdgeInterpolationAlgorithm::from(enum_value))
+    }
+}
+
+impl From<i32> for EdgeInterpolationAlgorithm {
+    fn from(i: i32) -> Self {
+        match i {
+            0 => EdgeInterpolationAlgorithm::SPHERICAL,
+            1 => EdgeInterpolationAlgorithm::VINCENTY,
+            2 => EdgeInterpolationAlgorithm::THOMAS,
+            3 => EdgeInterpolationAlgorithm::ANDOYER,
+            4 => EdgeInterpolationAlgorithm::KARNEY,
+            _ => EdgeInterpolationAlgorithm(i)
+        }
+    }
+}
+
+impl From<&i32> for EdgeInterpolationAlgorithm {
+    fn from(i: &i32) -> Self {
+        EdgeInterpolationAlgorithm::from(*i)
+    }
+}
+
+impl From<EdgeInterpolationAlgorithm> for i32 {
+    fn from(e: EdgeInterpolationAlgorithm) -> i32 {
+        e.0
+    }
+}
+
+impl From<&EdgeInterpolationAlgorithm> for i32 {
+    fn from(e: &EdgeInterpolationAlgorithm) -> i32 {
+        e.0
+    }
+}
+
+//
+// SizeStatistics
+//
+
+/// A structure for capturing metadata for estimating the unencoded,
+/// uncompressed size of data written. This is useful for readers to estimate
+/// how much memory is needed to reconstruct data in their memory model and for
+/// fine grained filter pushdown on nested structures (the histograms contained
+/// in this structure can help determine the number of nulls at a particular
+/// nesting level and maximum length of lists).
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SizeStatistics {
+    /// The number of physical bytes stored for BYTE_ARRAY data values assuming
+    /// no encoding. This is exclusive of the bytes needed to store the length of
+    /// each byte array. In other words, this field is equivalent to the `(size
+    /// of PLAIN-ENCODING the byte array values) - (4 bytes * number of values
+    /// written)`. To determine unencoded sizes of other types readers can use
+    /// schema information multiplied by the number of non-null and null values.
+    /// The number of null/non-null values can be inferred from the histograms
+    /// below.
+    ///
+    /// For example, if a column chunk is dictionary-encoded with dictionary
+    /// \["a", "bc", "cde"\], and a data page contains the indices \[0, 0, 1, 2\],
+    /// then this value for that data page should be 7 (1 + 1 + 2 + 3).
+    ///
+    /// This field should only be set for types that use BYTE_ARRAY as their
+    /// physical type.
+    pub unencoded_byte_array_data_bytes: Option<i64>,
+    /// When present, there is expected to be one element corresponding to each
+    /// repetition (i.e. size=max repetition_level+1) where each element
+    /// represents the number of times the repetition level was observed in the
+    /// data.
+    ///
+    /// This field may be omitted if max_repetition_level is 0 without loss
+    /// of information.
+    pub repetition_level_histogram: Option<Vec<i64>>,
+    /// Same as repetition_level_histogram except for definition levels.
+    ///
+    /// This field may be omitted if max_definition_level is 0 or 1 without
+    /// loss of information.
+    pub definition_level_histogram: Option<Vec<i64>>,
+}
+
+impl SizeStatistics {
+    pub fn new<F1, F2, F3>(unencoded_byte_array_data_bytes: F1, repetition_level_histogram: F2, definition_level_histogram: F3) -> SizeStatistics where F1: Into<Option<i64>>, F2: Into<Option<Vec<i64>>>, F3: Into<Option<Vec<i64>>> {
+        SizeStatistics {
+            unencoded_byte_array_data_bytes: unencoded_byte_array_data_bytes.into(),
+            repetition_level_histogram: repetition_level_histogram.into(),
+            definition_level_histogram: definition_level_histogram.into(),
+        }
+    }
+}
+
+impl TSerializable for SizeStatistics {
+    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<SizeStatistics> {
+        i_prot.read_struct_begin()?;
+        let mut f_1: Option<i64> = None;
+        let mut f_2: Option<Vec<i64>> = None;
+        let mut f_3: Option<Vec<i64>> = None;
+        loop {
+            let field_ident = i_prot.read_field_begin()?;
+            if field_ident.field_type == TType::Stop {
+                break;
+            }
+            let field_id = field_id(&field_ident)?;
+            match field_id {
+                1 => {
+                    let val = i_prot.read_i64()?;
+                    f_1 = Some(val);
+                },
+                2 => {
+                    let list_ident = i_prot.read_list_begin()?;
+                    let mut val: Vec<i64> = Vec::with_capacity(list_ident.size as usize);
+                    for _ in 0..list_ident.size {
+                        let list_elem_16 = i_prot.read_i64()?;
+                        val.push(list_elem_16);
+                    }
+                    i_prot.read_list_end()?;
+                    f_2 = Some(val);
+                },
+                3 => {
+                    let list_ident = i_prot.read_list_begin()?;
+                    let mut val: Vec<i64> = Vec::with_capacity(list_ident.size as usize);
+                    for _ in 0..list_ident.size {
+                        let list_elem_17 = i_prot.read_i64()?;
+                        val.push(list_elem_17);
+                    }
+                    i_prot.read_list_end()?;
+                    f_3 = Some(val);
+                },
+                _ => {
+                    i_prot.skip(field_ident.field_type)?;
+                },
+            };
+            i_prot.read_field_end()?;
+        }
+        i_prot.read_struct_end()?;
+        let ret = SizeStatistics {
+            unencoded_byte_array_data_bytes: f_1,
+            repetition_level_histogram: f_2,
+            definition_level_histogram: f_3,
+        };
+        Ok(ret)
+    }
+    fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+        let struct_ident = TStructIdentifier::new("SizeStatistics");
+        o_prot.write_struct_begin(&struct_ident)?;
+        if let Some(fld_var) = self.unencoded_byte_array_data_bytes {
+            o_prot.write_field_begin(&TFieldIdentifier::new("unencoded_byte_array_data_bytes", TType::I64, 1))?;
+            o_prot.write_i64(fld_var)?;
+            o_prot.write_field_end()?
+        }
+        if let Some(ref fld_var) = self.repetition_level_histogram {
+            o_prot.write_field_begin(&TFieldIdentifier::new("repetition_level_histogram", TType::List, 2))?;
+            o_prot.write_list_begin(&TListIdentifier::new(TType::I64, fld_var.len() as i32))?;
+            for e in fld_var {
+                o_prot.write_i64(*e)?;
+            }
+            o_prot.write_list_end()?;
+            o_prot.write_field_end()?
+        }
+        if let Some(ref fld_var) = self.definition_level_histogram {
+            o_prot.write_field_begin(&TFieldIdentifier::new("definition_level_histogram", TType::List, 3))?;
+            o_prot.write_list_begin(&TListIdentifier::new(TType::I64, fld_var.len() as i32))?;
+            for e in fld_var {
+                o_prot.write_i64(*e)?;
+            }
+            o_prot.write_list_end()?;
+            o_prot.write_field_end()?
+        }
+        o_prot.write_field_stop()?;
+        o_prot.write_struct_end()
+    }
+}
+
+//
+// BoundingBox
+//
+
+/// Bounding box for GEOMETRY or GEOGRAPHY type in the representation of min/max
+/// value pair of coordinates from each axis.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BoundingBox {
+    pub xmin: OrderedFloat<f64>,
+    pub xmax: OrderedFloat<f64>,
+    pub ymin: OrderedFloat<f64>,
+    pub ymax: OrderedFloat<f64>,
+    pub zmin: Option<OrderedFloat<f64>>,
+    pub zmax: Option<OrderedFloat<f64>>,
+    pub mmin: Option<OrderedFloat<f64>>,
+    pub mmax: Option<OrderedFloat<f64>>,
+}
+
+impl BoundingBox {
+    pub fn new<F5, F6, F7, F8>(xmin: OrderedFloat<f64>, xmax: OrderedFloat<f64>, ymin: OrderedFloat<f64>, ymax: OrderedFloat<f64>, zmin: F5, zmax: F6, mmin: F7, mmax: F8) -> BoundingBox where F5: Into<Option<OrderedFloat<f64>>>, F6: Into<Option<OrderedFloat<f64>>>, F7: Into<Option<OrderedFloat<f64>>>, F8: Into<Option<OrderedFloat<f64>>> {
+        BoundingBox {
+            xmin,
+            xmax,
+            ymin,
+            ymax,
+            zmin: zmin.into(),
+            zmax: zmax.into(),
+            mmin: mmin.into(),
+            mmax: mmax.into(),
+        }
+    }
+}
+
+impl TSerializable for BoundingBox {
+    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<BoundingBox> {
+        i_prot.read_struct_begin()?;
+        let mut f_1: Option<OrderedFloat<f64>> = None;
+        let mut f_2: Option<OrderedFloat<f64>> = None;
+        let mut f_3: Option<OrderedFloat<f64>> = None;
+        let mut f_4: Option<OrderedFloat<f64>> = None;
+        let mut f_5: Option<OrderedFloat<f64>> = None;
+        let mut f_6: Option<OrderedFloat<f64>> = None;
+        let mut f_7: Option<OrderedFloat<f64>> = None;
+        let mut f_8: Option<OrderedFloat<f64>> = None;
+        loop {
+            let field_ident = i_prot.read_field_begin()?;
+            if field_ident.field_type == TType::Stop {
+                break;
+            }
+            let field_id = field_id(&field_ident)?;
+            match field_id {
+                1 => {
+                    let val = OrderedFloat::from(i_prot.read_double()?);
+                    f_1 = Some(val);
+                },
+                2 => {
+                    let val = OrderedFloat::from(i_prot.read_double()?);
+                    f_2 = Some(val);
+                },
+                3 => {
+                    let val = OrderedFloat::from(i_prot.read_double()?);
+                    f_3 = Some(val);
+                },
+                4 => {
+                    let val = OrderedFloat::from(i_prot.read_double()?);
+                    f_4 = Some(val);
+                },
+                5 => {
+                    let val = OrderedFloat::from(i_prot.read_double()?);
+                    f_5 = Some(val);
+                },
+                6 => {
+                    let val = OrderedFloat::from(i_prot.read_double()?);
+                    f_6 = Some(val);
+                },
+                7 => {
+                    let val = OrderedFloat::from(i_prot.read_double()?);
+                    f_7 = Some(val);
+                },
+                8 => {
+                    let val = OrderedFloat::from(i_prot.read_double()?);
+                    f_8 = Some(val);
+                },
+                _ => {
+                    i_prot.skip(field_ident.field_type)?;
+                },
+            };
+            i_prot.read_field_end()?;
+        }
+        i_prot.read_struct_end()?;
+        verify_required_field_exists("BoundingBox.xmin", &f_1)?;
+        verify_required_field_exists("BoundingBox.xmax", &f_2)?;
+        verify_required_field_exists("BoundingBox.ymin", &f_3)?;
+        verify_required_field_exists("BoundingBox.ymax", &f_4)?;
+        let ret = BoundingBox {
+            xmin: f_1.expect("auto-generated code should have checked for presence of required fields"),
+            xmax: f_2.expect("auto-generated code should have checked for presence of required fields"),
+            ymin: f_3.expect("auto-generated code should have checked for presence of required fields"),
+            ymax: f_4.expect("auto-generated code should have checked for presence of required fields"),
+            zmin: f_5,
+            zmax: f_6,
+            mmin: f_7,
+            mmax: f_8,
+        };
+        Ok(ret)
+    }
+    fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+        let struct_ident = TStructIdentifier::new("BoundingBox");
+        o_prot.write_struct_begin(&struct_ident)?;
+        o_prot.write_field_begin(&TFieldIdentifier::new("xmin", TType::Double, 1))?;
+        o_prot.write_double(self.xmin.into())?;
+        o_prot.write_field_end()?;
+        o_prot.write_field_begin(&TFieldIdentifier::new("xmax", TType::Double, 2))?;
+        o_prot.write_double(self.xmax.into())?;
+        o_prot.write_field_end()?;
+        o_prot.write_field_begin(&TFieldIdentifier::new("ymin", TType::Double, 3))?;
+        o_prot.write_double(self.ymin.into())?;
+        o_prot.write_field_end()?;
+        o_prot.write_field_begin(&TFieldIdentifier::new("ymax", TType::Double, 4))?;
+        o_prot.write_double(self.ymax.into())?;
+        o_prot.write_field_end()?;
+        if let Some(fld_var) = self.zmin {
+            o_prot.write_field_begin(&TFieldIdentifier::new("zmin", TType::Double, 5))?;
+            o_prot.write_double(fld_var.into())?;
+            o_prot.write_field_end()?
+        }
+        if let Some(fld_var) = self.zmax {
+            o_prot.write_field_begin(&TFieldIdentifier::new("zmax", TType::Double, 6))?;
+            o_prot.write_double(fld_var.into())?;
+            o_prot.write_field_end()?
+        }
+        if let Some(fld_var) = self.mmin {
+            o_prot.write_field_begin(&TFieldIdentifier::new("mmin", TType::Double, 7))?;
+            o_prot.write_double(fld_var.into())?;
+            o_prot.write_field_end()?
+        }
+        if let Some(fld_var) = self.mmax {
+            o_prot.write_field_begin(&TFieldIdentifier::new("mmax", TType::Double, 8))?;
+            o_prot.write_double(fld_var.into())?;
+            o_prot.write_field_end()?
+        }
+        o_prot.write_field_stop()?;
+        o_prot.write_struct_end()
+    }
+}
+
+//
+// GeospatialStatistics
+//
+
+/// Statistics specific to GEOMETRY and GEOGRAPHY logical types
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GeospatialStatistics {
+    /// A bounding box of geospatial instances
+    pub bbox: Option<BoundingBox>,
+    /// Geospatial type codes of all instances, or an empty list if not known
+    pub geospatial_types: Option<Vec<i32>>,
+}
+
+impl GeospatialStatistics {
+    pub fn new<F1, F2>(bbox: F1, geospatial_types: F2) -> GeospatialStatistics where F1: Into<Option<BoundingBox>>, F2: Into<Option<Vec<i32>>> {
+        GeospatialStatistics {
+            bbox: bbox.into(),
+            geospatial_types: geospatial_types.into(),
+        }
+    }
+}
+
+impl TSerializable for GeospatialStatistics {
+    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<GeospatialStatistics> {
+        i_prot.read_struct_begin()?;
+        let mut f_1: Option<BoundingBox> = None;
+        let mut f_2: Option<Vec<i32>> = None;
+        loop {
+            let field_ident = i_prot.read_field_begin()?;
+            if field_ident.field_type == TType::Stop {
+                break;
+            }
+            let field_id = field_id(&field_ident)?;
+            match field_id {
+                1 => {
+                    let val = BoundingBox::read_from_in_protocol(i_prot)?;
+                    f_1 = Some(val);
+                },
+                2 => {
+                    let list_ident = i_prot.read_list_begin()?;
+                    let mut val: Vec<i32> = Vec::with_capacity(list_ident.size as usize);
+                    for _ in 0..list_ident.size {
+                        let list_elem_21 = i_prot.read_i32()?;
+                        val.push(list_elem_21);
+                    }
+                    i_prot.read_list_end()?;
+                    f_2 = Some(val);
+                },
+                _ => {
+                    i_prot.skip(field_ident.field_type)?;
+                },
+            };
+            i_prot.read_field_end()?;
+        }
+        i_prot.read_struct_end()?;
+        let ret = GeospatialStatistics {
+            bbox: f_1,
+            geospatial_types: f_2,
+        };
+        Ok(ret)
+    }
+    fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+        let struct_ident = TStructIdentifier::new("GeospatialStatistics");
+        o_prot.write_struct_begin(&struct_ident)?;
+        if let Some(ref fld_var) = self.bbox {
+            o_prot.write_field_begin(&TFieldIdentifier::new("bbox", TType::Struct, 1))?;
+            fld_var.write_to_out_protocol(o_prot)?;
+            o_prot.write_field_end()?
+        }
+        if let Some(ref fld_var) = self.geospatial_types {
+            o_prot.write_field_begin(&TFieldIdentifier::new("geospatial_types", TType::List, 2))?;
+            o_prot.write_list_begin(&TListIdentifier::new(TType::I32, fld_var.len() as i32))?;
+            for e in fld_var {
+                o_prot.write_i32(*e)?;
+            }
+            o_prot.write_list_end()?;
+            o_prot.write_field_end()?
+        }
+        o_prot.write_field_stop()?;
+        o_prot.write_struct_end()
+    }
+}
+
 //
 // Statistics
 //
@@ -662,10 +1088,17 @@ pub struct Statistics {
     /// arrays do not include a length prefix.
     pub max_value: Option<Vec<u8>>,
     pub min_value: Option<Vec<u8>>,
+    /// If true, max_value is the actual maximum value for a column
+    pub is_max_value_exact: Option<bool>,
+    /// If true, min_value is the actual minimum value for a column
+    pub is_min_value_exact: Option<bool>,
+    /// count of NaN values in the column; only present if physical type is FLOAT
+    /// or DOUBLE
+    pub nan_count: Option<i64>,
 }
 
 impl Statistics {
-    pub fn new<F1, F2, F3, F4, F5, F6>(max: F1, min: F2, null_count: F3, distinct_count: F4, max_value: F5, min_value: F6) -> Statistics where F1: Into<Option<Vec<u8>>>, F2: Into<Option<Vec<u8>>>, F3: Into<Option<i64>>, F4: Into<Option<i64>>, F5: Into<Option<Vec<u8>>>, F6: Into<Option<Vec<u8>>> {
+    pub fn new<F1, F2, F3, F4, F5, F6, F7, F8, F9>(max: F1, min: F2, null_count: F3, distinct_count: F4, max_value: F5, min_value: F6, is_max_value_exact: F7, is_min_value_exact: F8, nan_count: F9) -> Statistics where F1: Into<Option<Vec<u8>>>, F2: Into<Option<Vec<u8>>>, F3: Into<Option<i64>>, F4: Into<Option<i64>>, F5: Into<Option<Vec<u8>>>, F6: Into<Option<Vec<u8>>>, F7: Into<Option<bool>>, F8: Into<Option<bool>>, F9: Into<Option<i64>> {
         Statistics {
             max: max.into(),
             min: min.into(),
@@ -673,6 +1106,9 @@ impl Statistics {
             distinct_count: distinct_count.into(),
             max_value: max_value.into(),
             min_value: min_value.into(),
+            is_max_value_exact: is_max_value_exact.into(),
+            is_min_value_exact: is_min_value_exact.into(),
+            nan_count: nan_count.into(),
         }
     }
 }
@@ -686,6 +1122,9 @@ impl TSerializable for Statistics {
         let mut f_4: Option<i64> = None;
         let mut f_5: Option<Vec<u8>> = None;
         let mut f_6: Option<Vec<u8>> = None;
+        let mut f_7: Option<bool> = None;
+        let mut f_8: Option<bool> = None;
+        let mut f_9: Option<i64> = None;
         loop {
             let field_ident = i_prot.read_field_begin()?;
             if field_ident.field_type == TType::Stop {
@@ -717,6 +1156,18 @@ impl TSerializable for Statistics {
                     let val = i_prot.read_bytes()?;
                     f_6 = Some(val);
                 },
+                7 => {
+                    let val = i_prot.read_bool()?;
+                    f_7 = Some(val);
+                },
+                8 => {
+                    let val = i_prot.read_bool()?;
+                    f_8 = Some(val);
+                },
+                9 => {
+                    let val = i_prot.read_i64()?;
+                    f_9 = Some(val);
+                },
                 _ => {
                     i_prot.skip(field_ident.field_type)?;
                 },
@@ -731,6 +1182,9 @@ impl TSerializable for Statistics {
             distinct_count: f_4,
             max_value: f_5,
             min_value: f_6,
+            is_max_value_exact: f_7,
+            is_min_value_exact: f_8,
+            nan_count: f_9,
         };
         Ok(ret)
     }
@@ -767,6 +1221,21 @@ impl TSerializable for Statistics {
             o_prot.write_bytes(fld_var)?;
             o_prot.write_field_end()?
         }
+        if let Some(fld_var) = self.is_max_value_exact {
+            o_prot.write_field_begin(&TFieldIdentifier::new("is_max_value_exact", TType::Bool, 7))?;
+            o_prot.write_bool(fld_var)?;
+            o_prot.write_field_end()?
+        }
+        if let Some(fld_var) = self.is_min_value_exact {
+            o_prot.write_field_begin(&TFieldIdentifier::new("is_min_value_exact", TType::Bool, 8))?;
+            o_prot.write_bool(fld_var)?;
+            o_prot.write_field_end()?
+        }
+        if let Some(fld_var) = self.nan_count {
+            o_prot.write_field_begin(&TFieldIdentifier::new("nan_count", TType::I64, 9))?;
+            o_prot.write_i64(fld_var)?;
+            o_prot.write_field_end()?
+        }
         o_prot.write_field_stop()?;
         o_prot.write_struct_end()
     }
@@ -781,6 +1250,9 @@ impl Default for Statistics {
             distinct_count: Some(0),
             max_value: Some(Vec::new()),
             min_value: Some(Vec::new()),
+            is_max_value_exact: Some(false),
+            is_min_value_exact: Some(false),
+            nan_count: Some(0),
         }
     }
 }
@@ -882,6 +1354,112 @@ impl Default for UUIDType {
     }
 }
 
+//
+// Float16Type
+//
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Float16Type {
+}
+
+impl Float16Type {
+    pub fn new() -> Float16Type {
+        Float16Type {}
+    }
+}
+
+impl TSerializable for Float16Type {
+    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Float16Type> {
+        i_prot.read_struct_begin()?;
+        loop {
+            let field_ident = i_prot.read_field_begin()?;
+            if field_ident.field_type == TType::Stop {
+                break;
+            }
+            let field_id = field_id(&field_ident)?;
+            match field_id {
+                _ => {
+                    i_prot.skip(field_ident.field_type)?;
+                },
+            };
+            i_prot.read_field_end()?;
+        }
+        i_prot.read_struct_end()?;
+        let ret = Float16Type {};
+        Ok(ret)
+    }
+    fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+        let struct_ident = TStructIdentifier::new("Float16Type");
+        o_prot.write_struct_begin(&struct_ident)?;
+        o_prot.write_field_stop()?;
+        o_prot.write_struct_end()
+    }
+}
+
+impl Default for Float16Type {
+    fn default() -> Self {
+        Float16Type{}
+    }
+}
+
+//
+// VariantType
+//
+
+/// Embedded Variant logical type annotation
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct VariantType {
+    pub specification_version: Option<i8>,
+}
+
+impl VariantType {
+    pub fn new<F1>(specification_version: F1) -> VariantType where F1: Into<Option<i8>> {
+        VariantType {
+            specification_version: specification_version.into(),
+        }
+    }
+}
+
+impl TSerializable for VariantType {
+    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<VariantType> {
+        i_prot.read_struct_begin()?;
+        let mut f_1: Option<i8> = None;
+        loop {
+            let field_ident = i_prot.read_field_begin()?;
+            if field_ident.field_type == TType::Stop {
+                break;
+            }
+            let field_id = field_id(&field_ident)?;
+            match field_id {
+                1 => {
+                    let val = i_prot.read_i8()?;
+                    f_1 = Some(val);
+                },
+                _ => {
+                    i_prot.skip(field_ident.field_type)?;
+                },
+            };
+            i_prot.read_field_end()?;
+        }
+        i_prot.read_struct_end()?;
+        let ret = VariantType {
+            specification_version: f_1,
+        };
+        Ok(ret)
+    }
+    fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+        let struct_ident = TStructIdentifier::new("VariantType");
+        o_prot.write_struct_begin(&struct_ident)?;
+        if let Some(fld_var) = self.specification_version {
+            o_prot.write_field_begin(&TFieldIdentifier::new("specification_version", TType::I08, 1))?;
+            o_prot.write_i8(fld_var)?;
+            o_prot.write_field_end()?
+        }
+        o_prot.write_field_stop()?;
+        o_prot.write_struct_end()
+    }
+}
+
 //
 // MapType
 //
@@ -1417,7 +1995,14 @@ impl TSerializable for TimeUnit {
                 )
             )
         } else {
-            Ok(ret.expect("return value should have been constructed"))
+            ret.ok_or_else(|| {
+                thrift::Error::Protocol(
+                    ProtocolError::new(
+                        ProtocolErrorKind::InvalidData,
+                        "received unknown member of union from remote TimeUnit"
+                    )
+                )
+            })
         }
     }
     fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
@@ -1762,6 +2347,145 @@ impl Default for BsonType {
     }
 }
 
+//
+// GeometryType
+//
+
+/// Embedded Geometry logical type annotation
+///
+/// Geospatial features in the Well-Known Binary (WKB) format and edges interpolation
+/// is always linear/planar.
+///
+/// Allowed for physical types: BYTE_ARRAY
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GeometryType {
+    pub crs: Option<String>,
+}
+
+impl GeometryType {
+    pub fn new<F1>(crs: F1) -> GeometryType where F1: Into<Option<String>> {
+        GeometryType {
+            crs: crs.into(),
+        }
+    }
+}
+
+impl TSerializable for GeometryType {
+    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<GeometryType> {
+        i_prot.read_struct_begin()?;
+        let mut f_1: Option<String> = None;
+        loop {
+            let field_ident = i_prot.read_field_begin()?;
+            if field_ident.field_type == TType::Stop {
+                break;
+            }
+            let field_id = field_id(&field_ident)?;
+            match field_id {
+                1 => {
+                    let val = i_prot.read_string()?;
+                    f_1 = Some(val);
+                },
+                _ => {
+                    i_prot.skip(field_ident.field_type)?;
+                },
+            };
+            i_prot.read_field_end()?;
+        }
+        i_prot.read_struct_end()?;
+        let ret = GeometryType {
+            crs: f_1,
+        };
+        Ok(ret)
+    }
+    fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+        let struct_ident = TStructIdentifier::new("GeometryType");
+        o_prot.write_struct_begin(&struct_ident)?;
+        if let Some(ref fld_var) = self.crs {
+            o_prot.write_field_begin(&TFieldIdentifier::new("crs", TType::String, 1))?;
+            o_prot.write_string(fld_var)?;
+            o_prot.write_field_end()?
+        }
+        o_prot.write_field_stop()?;
+        o_prot.write_struct_end()
+    }
+}
+
+//
+// GeographyType
+//
+
+/// Embedded Geography logical type annotation
+///
+/// Geospatial features in the WKB format with an explicit (non-linear/non-planar)
+/// edges interpolation algorithm.
+///
+/// Allowed for physical types: BYTE_ARRAY
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct GeographyType {
+    pub crs: Option<String>,
+    pub algorithm: Option<EdgeInterpolationAlgorithm>,
+}
+
+impl GeographyType {
+    pub fn new<F1, F2>(crs: F1, algorithm: F2) -> GeographyType where F1: Into<Option<String>>, F2: Into<Option<EdgeInterpolationAlgorithm>> {
+        GeographyType {
+            crs: crs.into(),
+            algorithm: algorithm.into(),
+        }
+    }
+}
+
+impl TSerializable for GeographyType {
+    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<GeographyType> {
+        i_prot.read_struct_begin()?;
+        let mut f_1: Option<String> = None;
+        let mut f_2: Option<EdgeInterpolationAlgorithm> = None;
+        loop {
+            let field_ident = i_prot.read_field_begin()?;
+            if field_ident.field_type == TType::Stop {
+                break;
+            }
+            let field_id = field_id(&field_ident)?;
+            match field_id {
+                1 => {
+                    let val = i_prot.read_string()?;
+                    f_1 = Some(val);
+                },
+                2 => {
+                    let val = EdgeInterpolationAlgorithm::read_from_in_protocol(i_prot)?;
+                    f_2 = Some(val);
+                },
+                _ => {
+                    i_prot.skip(field_ident.field_type)?;
+                },
+            };
+            i_prot.read_field_end()?;
+        }
+        i_prot.read_struct_end()?;
+        let ret = GeographyType {
+            crs: f_1,
+            algorithm: f_2,
+        };
+        Ok(ret)
+    }
+    fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
+        let struct_ident = TStructIdentifier::new("GeographyType");
+        o_prot.write_struct_begin(&struct_ident)?;
+        if let Some(ref fld_var) = self.crs {
+            o_prot.write_field_begin(&TFieldIdentifier::new("crs", TType::String, 1))?;
+            o_prot.write_string(fld_var)?;
+            o_prot.write_field_end()?
+        }
+        if let Some(fld_var) = self.algorithm {
+            o_prot.write_field_begin(&TFieldIdentifier::new("algorithm", TType::I32, 2))?;
+            fld_var.write_to_out_protocol(o_prot)?;
+            o_prot.write_field_end()?
+        }
+        o_prot.write_field_stop()?;
+        o_prot.write_struct_end()
+    }
+}
+
 //
 // LogicalType
 //
@@ -1781,10 +2505,16 @@ pub enum LogicalType {
     JSON(JsonType),
     BSON(BsonType),
     UUID(UUIDType),
+    FLOAT16(Float16Type),
+    VARIANT(VariantType),
+    GEOMETRY(GeometryType),
+    GEOGRAPHY(GeographyType),
 }
 
-impl TSerializable for LogicalType {
-    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<LogicalType> {
+impl LogicalType {
+    /// Like `read_from_in_protocol`, but returns `None` for a member this reader does not
+    /// know, e.g. a logical type added by a newer version of the format.
+    pub fn read_optional_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Option<LogicalType>> {
         let mut ret: Option<LogicalType> = None;
         let mut received_field_count = 0;
         i_prot.read_struct_begin()?;
@@ -1886,6 +2616,34 @@ impl TSerializable for LogicalType {
                     }
                     received_field_count += 1;
                 },
+                15 => {
+                    let val = Float16Type::read_from_in_protocol(i_prot)?;
+                    if ret.is_none() {
+                        ret = Some(LogicalType::FLOAT16(val));
+                    }
+                    received_field_count += 1;
+                },
+                16 => {
+                    let val = VariantType::read_from_in_protocol(i_prot)?;
+                    if ret.is_none() {
+                        ret = Some(LogicalType::VARIANT(val));
+                    }
+                    received_field_count += 1;
+                },
+                17 => {
+                    let val = GeometryType::read_from_in_protocol(i_prot)?;
+                    if ret.is_none() {
+                        ret = Some(LogicalType::GEOMETRY(val));
+                    }
+                    received_field_count += 1;
+                },
+                18 => {
+                    let val = GeographyType::read_from_in_protocol(i_prot)?;
+                    if ret.is_none() {
+                        ret = Some(LogicalType::GEOGRAPHY(val));
+                    }
+                    received_field_count += 1;
+                },
                 _ => {
                     i_prot.skip(field_ident.field_type)?;
                     received_field_count += 1;
@@ -1913,9 +2671,22 @@ impl TSerializable for LogicalType {
                 )
             )
         } else {
-            Ok(ret.expect("return value should have been constructed"))
+            Ok(ret)
         }
     }
+}
+
+impl TSerializable for LogicalType {
+    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<LogicalType> {
+        LogicalType::read_optional_from_in_protocol(i_prot)?.ok_or_else(|| {
+            thrift::Error::Protocol(
+                ProtocolError::new(
+                    ProtocolErrorKind::InvalidData,
+                    "received unknown member of union from remote LogicalType"
+                )
+            )
+        })
+    }
     fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
         let struct_ident = TStructIdentifier::new("LogicalType");
         o_prot.write_struct_begin(&struct_ident)?;
@@ -1985,6 +2756,26 @@ impl TSerializable for LogicalType {
                 f.write_to_out_protocol(o_prot)?;
                 o_prot.write_field_end()?;
             },
+            LogicalType::FLOAT16(ref f) => {
+                o_prot.write_field_begin(&TFieldIdentifier::new("FLOAT16", TType::Struct, 15))?;
+                f.write_to_out_protocol(o_prot)?;
+                o_prot.write_field_end()?;
+            },
+            LogicalType::VARIANT(ref f) => {
+                o_prot.write_field_begin(&TFieldIdentifier::new("VARIANT", TType::Struct, 16))?;
+                f.write_to_out_protocol(o_prot)?;
+                o_prot.write_field_end()?;
+            },
+            LogicalType::GEOMETRY(ref f) => {
+                o_prot.write_field_begin(&TFieldIdentifier::new("GEOMETRY", TType::Struct, 17))?;
+                f.write_to_out_protocol(o_prot)?;
+                o_prot.write_field_end()?;
+            },
+            LogicalType::GEOGRAPHY(ref f) => {
+                o_prot.write_field_begin(&TFieldIdentifier::new("GEOGRAPHY", TType::Struct, 18))?;
+                f.write_to_out_protocol(o_prot)?;
+                o_prot.write_field_end()?;
+            },
         }
         o_prot.write_field_stop()?;
         o_prot.write_struct_end()
@@ -2113,8 +2904,8 @@ impl TSerializable for SchemaElement {
                     f_9 = Some(val);
                 },
                 10 => {
-                    let val = LogicalType::read_from_in_protocol(i_prot)?;
-                    f_10 = Some(val);
+                    // Logical types unknown to this reader are ignored, like unset ones.
+                    f_10 = LogicalType::read_optional_from_in_protocol(i_prot)?;
                 },
                 _ => {
                     i_prot.skip(field_ident.field_type)?;
@@ -2700,7 +3491,14 @@ impl TSerializable for BloomFilterAlgorithm {
                 )
             )
         } else {
-            Ok(ret.expect("return value should have been constructed"))
+            ret.ok_or_else(|| {
+                thrift::Error::Protocol(
+                    ProtocolError::new(
+                        ProtocolErrorKind::InvalidData,
+                        "received unknown member of union from remote BloomFilterAlgorithm"
+                    )
+                )
+            })
         }
     }
     fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
@@ -2824,7 +3622,14 @@ impl TSerializable for BloomFilterHash {
                 )
             )
         } else {
-            Ok(ret.expect("return value should have been constructed"))
+            ret.ok_or_else(|| {
+                thrift::Error::Protocol(
+                    ProtocolError::new(
+                        ProtocolErrorKind::InvalidData,
+                        "received unknown member of union from remote BloomFilterHash"
+                    )
+                )
+            })
         }
     }
     fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
@@ -2947,7 +3752,14 @@ impl TSerializable for BloomFilterCompression {
                 )
             )
         } else {
-            Ok(ret.expect("return value should have been constructed"))
+            ret.ok_or_else(|| {
+                thrift::Error::Protocol(
+                    ProtocolError::new(
+                        ProtocolErrorKind::InvalidData,
+                        "received unknown member of union from remote BloomFilterCompression"
+                    )
+                )
+            })
         }
     }
     fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
@@ -3516,10 +4328,23 @@ pub struct ColumnMetaData {
     pub encoding_stats: Option<Vec<PageEncodingStats>>,
     /// Byte offset from beginning of file to Bloom filter data. *
     pub bloom_filter_offset: Option<i64>,
+    /// Size of Bloom filter data including the serialized header, in bytes.
+    /// Added in 2.10 so readers may not read this field from old files and
+    /// it can be obtained after the BloomFilterHeader has been deserialized.
+    /// Writers should write this field so readers can read the bloom filter
+    /// in a single I/O.
+    pub bloom_filter_length: Option<i32>,
+    /// Optional statistics to help estimate total memory when converted to in-memory
+    /// representations. The histograms contained in these statistics can
+    /// also be useful in some cases for more fine-grained nullability/list length
+    /// filter pushdown.
+    pub size_statistics: Option<SizeStatistics>,
+    /// Optional statistics specific for Geometry and Geography logical types
+    pub geospatial_statistics: Option<GeospatialStatistics>,
 }
 
 impl ColumnMetaData {
-    pub fn new<F8, F10, F11, F12, F13, F14>(type_: Type, encodings: Vec<Encoding>, path_in_schema: Vec<String>, codec: CompressionCodec, num_values: i64, total_uncompressed_size: i64, total_compressed_size: i64, key_value_metadata: F8, data_page_offset: i64, index_page_offset: F10, dictionary_page_offset: F11, statistics: F12, encoding_stats: F13, bloom_filter_offset: F14) -> ColumnMetaData where F8: Into<Option<Vec<KeyValue>>>, F10: Into<Option<i64>>, F11: Into<Option<i64>>, F12: Into<Option<Statistics>>, F13: Into<Option<Vec<PageEncodingStats>>>, F14: Into<Option<i64>> {
+    pub fn new<F8, F10, F11, F12, F13, F14, F15, F16, F17>(type_: Type, encodings: Vec<Encoding>, path_in_schema: Vec<String>, codec: CompressionCodec, num_values: i64, total_uncompressed_size: i64, total_compressed_size: i64, key_value_metadata: F8, data_page_offset: i64, index_page_offset: F10, dictionary_page_offset: F11, statistics: F12, encoding_stats: F13, bloom_filter_offset: F14, bloom_filter_length: F15, size_statistics: F16, geospatial_statistics: F17) -> ColumnMetaData where F8: Into<Option<Vec<KeyValue>>>, F10: Into<Option<i64>>, F11: Into<Option<i64>>, F12: Into<Option<Statistics>>, F13: Into<Option<Vec<PageEncodingStats>>>, F14: Into<Option<i64>>, F15: Into<Option<i32>>, F16: Into<Option<SizeStatistics>>, F17: Into<Option<GeospatialStatistics>> {
         ColumnMetaData {
             type_,
             encodings,
@@ -3535,6 +4360,9 @@ impl ColumnMetaData {
             statistics: statistics.into(),
             encoding_stats: encoding_stats.into(),
             bloom_filter_offset: bloom_filter_offset.into(),
+            bloom_filter_length: bloom_filter_length.into(),
+            size_statistics: size_statistics.into(),
+            geospatial_statistics: geospatial_statistics.into(),
         }
     }
 }
@@ -3556,6 +4384,9 @@ impl TSerializable for ColumnMetaData {
         let mut f_12: Option<Statistics> = None;
         let mut f_13: Option<Vec<PageEncodingStats>> = None;
         let mut f_14: Option<i64> = None;
+        let mut f_15: Option<i32> = None;
+        let mut f_16: Option<SizeStatistics> = None;
+        let mut f_17: Option<GeospatialStatistics> = None;
         loop {
             let field_ident = i_prot.read_field_begin()?;
             if field_ident.field_type == TType::Stop {
@@ -3643,6 +4474,18 @@ impl TSerializable for ColumnMetaData {
                     let val = i_prot.read_i64()?;
                     f_14 = Some(val);
                 },
+                15 => {
+                    let val = i_prot.read_i32()?;
+                    f_15 = Some(val);
+                },
+                16 => {
+                    let val = SizeStatistics::read_from_in_protocol(i_prot)?;
+                    f_16 = Some(val);
+                },
+                17 => {
+                    let val = GeospatialStatistics::read_from_in_protocol(i_prot)?;
+                    f_17 = Some(val);
+                },
                 _ => {
                     i_prot.skip(field_ident.field_type)?;
                 },
@@ -3673,6 +4516,9 @@ impl TSerializable for ColumnMetaData {
             statistics: f_12,
             encoding_stats: f_13,
             bloom_filter_offset: f_14,
+            bloom_filter_length: f_15,
+            size_statistics: f_16,
+            geospatial_statistics: f_17,
         };
         Ok(ret)
     }
@@ -3749,6 +4595,21 @@ impl TSerializable for ColumnMetaData {
             o_prot.write_i64(fld_var)?;
             o_prot.write_field_end()?
         }
+        if let Some(fld_var) = self.bloom_filter_length {
+            o_prot.write_field_begin(&TFieldIdentifier::new("bloom_filter_length", TType::I32, 15))?;
+            o_prot.write_i32(fld_var)?;
+            o_prot.write_field_end()?
+        }
+        if let Some(ref fld_var) = self.size_statistics {
+            o_prot.write_field_begin(&TFieldIdentifier::new("size_statistics", TType::Struct, 16))?;
+            fld_var.write_to_out_protocol(o_prot)?;
+            o_prot.write_field_end()?
+        }
+        if let Some(ref fld_var) = self.geospatial_statistics {
+            o_prot.write_field_begin(&TFieldIdentifier::new("geospatial_statistics", TType::Struct, 17))?;
+            fld_var.write_to_out_protocol(o_prot)?;
+            o_prot.write_field_end()?
+        }
         o_prot.write_field_stop()?;
         o_prot.write_struct_end()
     }
@@ -3946,7 +4807,14 @@ impl TSerializable for ColumnCryptoMetaData {
                 )
             )
         } else {
-            Ok(ret.expect("return value should have been constructed"))
+            ret.ok_or_else(|| {
+                thrift::Error::Protocol(
+                    ProtocolError::new(
+                        ProtocolErrorKind::InvalidData,
+                        "received unknown member of union from remote ColumnCryptoMetaData"
+                    )
+                )
+            })
         }
     }
     fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
@@ -4365,8 +5233,10 @@ pub enum ColumnOrder {
     TYPEORDER(TypeDefinedOrder),
 }
 
-impl TSerializable for ColumnOrder {
-    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<ColumnOrder> {
+impl ColumnOrder {
+    /// Like `read_from_in_protocol`, but returns `None` for a member this reader does not
+    /// know, e.g. a column order added by a newer version of the format.
+    pub fn read_optional_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<Option<ColumnOrder>> {
         let mut ret: Option<ColumnOrder> = None;
         let mut received_field_count = 0;
         i_prot.read_struct_begin()?;
@@ -4411,9 +5281,22 @@ impl TSerializable for ColumnOrder {
                 )
             )
         } else {
-            Ok(ret.expect("return value should have been constructed"))
+            Ok(ret)
         }
     }
+}
+
+impl TSerializable for ColumnOrder {
+    fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<ColumnOrder> {
+        ColumnOrder::read_optional_from_in_protocol(i_prot)?.ok_or_else(|| {
+            thrift::Error::Protocol(
+                ProtocolError::new(
+                    ProtocolErrorKind::InvalidData,
+                    "received unknown member of union from remote ColumnOrder"
+                )
+            )
+        })
+    }
     fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
         let struct_ident = TStructIdentifier::new("ColumnOrder");
         o_prot.write_struct_begin(&struct_ident)?;
@@ -4523,12 +5406,18 @@ pub struct OffsetIndex {
     /// PageLocations, ordered by increasing PageLocation.offset. It is required
     /// that page_locations\[i\].first_row_index < page_locations\[i+1\].first_row_index.
     pub page_locations: Vec<PageLocation>,
+    /// Unencoded/uncompressed size for BYTE_ARRAY types.
+    ///
+    /// See documention for unencoded_byte_array_data_bytes in SizeStatistics for
+    /// more details on this field.
+    pub unencoded_byte_array_data_bytes: Option<Vec<i64>>,
 }
 
 impl OffsetIndex {
-    pub fn new(page_locations: Vec<PageLocation>) -> OffsetIndex {
+    pub fn new<F2>(page_locations: Vec<PageLocation>, unencoded_byte_array_data_bytes: F2) -> OffsetIndex where F2: Into<Option<Vec<i64>>> {
         OffsetIndex {
             page_locations,
+            unencoded_byte_array_data_bytes: unencoded_byte_array_data_bytes.into(),
         }
     }
 }
@@ -4537,6 +5426,7 @@ impl TSerializable for OffsetIndex {
     fn read_from_in_protocol(i_prot: &mut dyn TInputProtocol) -> thrift::Result<OffsetIndex> {
         i_prot.read_struct_begin()?;
         let mut f_1: Option<Vec<PageLocation>> = None;
+        let mut f_2: Option<Vec<i64>> = None;
         loop {
             let field_ident = i_prot.read_field_begin()?;
             if field_ident.field_type == TType::Stop {
@@ -4554,6 +5444,16 @@ impl TSerializable for OffsetIndex {
                     i_prot.read_list_end()?;
                     f_1 = Some(val);
                 },
+                2 => {
+                    let list_ident = i_prot.read_list_begin()?;
+                    let mut val: Vec<i64> = Vec::with_capacity(list_ident.size as usize);
+                    for _ in 0..list_ident.size {
+                        let list_elem_18 = i_prot.read_i64()?;
+                        val.push(list_elem_18);
+                    }
+                    i_prot.read_list_end()?;
+                    f_2 = Some(val);
+                },
                 _ => {
                     i_prot.skip(field_ident.field_type)?;
                 },
@@ -4564,6 +5464,7 @@ impl TSerializable for OffsetIndex {
         verify_required_field_exists("OffsetIndex.page_locations", &f_1)?;
         let ret = OffsetIndex {
             page_locations: f_1.expect("auto-generated code should have checked for presence of required fields"),
+            unencoded_byte_array_data_bytes: f_2,
         };
         Ok(ret)
     }
@@ -4577,6 +5478,15 @@ impl TSerializable for OffsetIndex {
         }
         o_prot.write_list_end()?;
         o_prot.write_field_end()?;
+        if let Some(ref fld_var) = self.unencoded_byte_array_data_bytes {
+            o_prot.write_field_begin(&TFieldIdentifier::new("unencoded_byte_array_data_bytes", TType::List, 2))?;
+            o_prot.write_list_begin(&TListIdentifier::new(TType::I64, fld_var.len() as i32))?;
+            for e in fld_var {
+                o_prot.write_i64(*e)?;
+            }
+            o_prot.write_list_end()?;
+            o_prot.write_field_end()?
+        }
         o_prot.write_field_stop()?;
         o_prot.write_struct_end()
     }
@@ -4612,16 +5522,35 @@ pub struct ColumnIndex {
     pub boundary_order: BoundaryOrder,
     /// A list containing the number of null values for each page *
     pub null_counts: Option<Vec<i64>>,
+    /// Contains repetition level histograms for each page
+    /// concatenated together.  The repetition_level_histogram field on
+    /// SizeStatistics contains more details.
+    ///
+    /// When present the length should always be (number of pages *
+    /// (max_repetition_level + 1)) elements.
+    ///
+    /// Element 0 is the first element of the histogram for the first page.
+    /// Element (max_repetition_level + 1) is the first element of the histogram
+    /// for the second page.
+    pub repetition_level_histograms: Option<Vec<i64>>,
+    /// Same as repetition_level_histograms except for definitions levels.
+    pub definition_level_histograms: Option<Vec<i64>>,
+    /// A list containing the number of NaN values for each page. Only present
+    /// for columns of physical type FLOAT or DOUBLE.
+    pub nan_counts: Option<Vec<i64>>,
 }
 
 impl ColumnIndex {
-    pub fn new<F5>(null_pages: Vec<bool>, min_values: Vec<Vec<u8>>, max_values: Vec<Vec<u8>>, boundary_order: BoundaryOrder, null_counts: F5) -> ColumnIndex where F5: Into<Option<Vec<i64>>> {
+    pub fn new<F5, F6, F7, F8>(null_pages: Vec<bool>, min_values: Vec<Vec<u8>>, max_values: Vec<Vec<u8>>, boundary_order: BoundaryOrder, null_counts: F5, repetition_level_histograms: F6, definition_level_histograms: F7, nan_counts: F8) -> ColumnIndex where F5: Into<Option<Vec<i64>>>, F6: Into<Option<Vec<i64>>>, F7: Into<Option<Vec<i64>>>, F8: Into<Option<Vec<i64>>> {
         ColumnIndex {
             null_pages,
             min_values,
             max_values,
             boundary_order,
             null_counts: null_counts.into(),
+            repetition_level_histograms: repetition_level_histograms.into(),
+            definition_level_histograms: definition_level_histograms.into(),
+            nan_counts: nan_counts.into(),
         }
     }
 }
@@ -4634,6 +5563,9 @@ impl TSerializable for ColumnIndex {
         let mut f_3: Option<Vec<Vec<u8>>> = None;
         let mut f_4: Option<BoundaryOrder> = None;
         let mut f_5: Option<Vec<i64>> = None;
+        let mut f_6: Option<Vec<i64>> = None;
+        let mut f_7: Option<Vec<i64>> = None;
+        let mut f_8: Option<Vec<i64>> = None;
         loop {
             let field_ident = i_prot.read_field_begin()?;
             if field_ident.field_type == TType::Stop {
@@ -4685,6 +5617,36 @@ impl TSerializable for ColumnIndex {
                     i_prot.read_list_end()?;
                     f_5 = Some(val);
                 },
+                6 => {
+                    let list_ident = i_prot.read_list_begin()?;
+                    let mut val: Vec<i64> = Vec::with_capacity(list_ident.size as usize);
+                    for _ in 0..list_ident.size {
+                        let list_elem_19 = i_prot.read_i64()?;
+                        val.push(list_elem_19);
+                    }
+                    i_prot.read_list_end()?;
+                    f_6 = Some(val);
+                },
+                7 => {
+                    let list_ident = i_prot.read_list_begin()?;
+                    let mut val: Vec<i64> = Vec::with_capacity(list_ident.size as usize);
+                    for _ in 0..list_ident.size {
+                        let list_elem_20 = i_prot.read_i64()?;
+                        val.push(list_elem_20);
+                    }
+                    i_prot.read_list_end()?;
+                    f_7 = Some(val);
+                },
+                8 => {
+                    let list_ident = i_prot.read_list_begin()?;
+                    let mut val: Vec<i64> = Vec::with_capacity(list_ident.size as usize);
+                    for _ in 0..list_ident.size {
+                        let list_elem_12 = i_prot.read_i64()?;
+                        val.push(list_elem_12);
+                    }
+                    i_prot.read_list_end()?;
+                    f_8 = Some(val);
+                },
                 _ => {
                     i_prot.skip(field_ident.field_type)?;
                 },
@@ -4702,6 +5664,9 @@ impl TSerializable for ColumnIndex {
             max_values: f_3.expect("auto-generated code should have checked for presence of required fields"),
             boundary_order: f_4.expect("auto-generated code should have checked for presence of required fields"),
             null_counts: f_5,
+            repetition_level_histograms: f_6,
+            definition_level_histograms: f_7,
+            nan_counts: f_8,
         };
         Ok(ret)
     }
@@ -4741,6 +5706,33 @@ impl TSerializable for ColumnIndex {
             o_prot.write_list_end()?;
             o_prot.write_field_end()?
         }
+        if let Some(ref fld_var) = self.repetition_level_histograms {
+            o_prot.write_field_begin(&TFieldIdentifier::new("repetition_level_histograms", TType::List, 6))?;
+            o_prot.write_list_begin(&TListIdentifier::new(TType::I64, fld_var.len() as i32))?;
+            for e in fld_var {
+                o_prot.write_i64(*e)?;
+            }
+            o_prot.write_list_end()?;
+            o_prot.write_field_end()?
+        }
+        if let Some(ref fld_var) = self.definition_level_histograms {
+            o_prot.write_field_begin(&TFieldIdentifier::new("definition_level_histograms", TType::List, 7))?;
+            o_prot.write_list_begin(&TListIdentifier::new(TType::I64, fld_var.len() as i32))?;
+            for e in fld_var {
+                o_prot.write_i64(*e)?;
+            }
+            o_prot.write_list_end()?;
+            o_prot.write_field_end()?
+        }
+        if let Some(ref fld_var) = self.nan_counts {
+            o_prot.write_field_begin(&TFieldIdentifier::new("nan_counts", TType::List, 8))?;
+            o_prot.write_list_begin(&TListIdentifier::new(TType::I64, fld_var.len() as i32))?;
+            for e in fld_var {
+                o_prot.write_i64(*e)?;
+            }
+            o_prot.write_list_end()?;
+            o_prot.write_field_end()?
+        }
         o_prot.write_field_stop()?;
         o_prot.write_struct_end()
     }
@@ -5003,7 +5995,14 @@ impl TSerializable for EncryptionAlgorithm {
                 )
             )
         } else {
-            Ok(ret.expect("return value should have been constructed"))
+            ret.ok_or_else(|| {
+                thrift::Error::Protocol(
+                    ProtocolError::new(
+                        ProtocolErrorKind::InvalidData,
+                        "received unknown member of union from remote EncryptionAlgorithm"
+                    )
+                )
+            })
         }
     }
     fn write_to_out_protocol(&self, o_prot: &mut dyn TOutputProtocol) -> thrift::Result<()> {
@@ -5064,7 +6063,9 @@ pub struct FileMetaData {
     ///
     /// The obsolete min and max fields are always sorted by signed comparison
     /// regardless of column_orders.
-    pub column_orders: Option<Vec<ColumnOrder>>,
+    ///
+    /// Column orders unknown to this reader are `None`.
+    pub column_orders: Option<Vec<Option<ColumnOrder>>>,
     /// Encryption algorithm. This field is set only in encrypted files
     /// with plaintext footer. Files with encrypted footer store algorithm id
     /// in FileCryptoMetaData structure.
@@ -5075,7 +6076,7 @@ pub struct FileMetaData {
 }
 
 impl FileMetaData {
-    pub fn new<F5, F6, F7, F8, F9>(version: i32, schema: Vec<SchemaElement>, num_rows: i64, row_groups: Vec<RowGroup>, key_value_metadata: F5, created_by: F6, column_orders: F7, encryption_algorithm: F8, footer_signing_key_metadata: F9) -> FileMetaData where F5: Into<Option<Vec<KeyValue>>>, F6: Into<Option<String>>, F7: Into<Option<Vec<ColumnOrder>>>, F8: Into<Option<EncryptionAlgorithm>>, F9: Into<Option<Vec<u8>>> {
+    pub fn new<F5, F6, F7, F8, F9>(version: i32, schema: Vec<SchemaElement>, num_rows: i64, row_groups: Vec<RowGroup>, key_value_metadata: F5, created_by: F6, column_orders: F7, encryption_algorithm: F8, footer_signing_key_metadata: F9) -> FileMetaData where F5: Into<Option<Vec<KeyValue>>>, F6: Into<Option<String>>, F7: Into<Option<Vec<Option<ColumnOrder>>>>, F8: Into<Option<EncryptionAlgorithm>>, F9: Into<Option<Vec<u8>>> {
         FileMetaData {
             version,
             schema,
@@ -5099,7 +6100,7 @@ impl TSerializable for FileMetaData {
         let mut f_4: Option<Vec<RowGroup>> = None;
         let mut f_5: Option<Vec<KeyValue>> = None;
         let mut f_6: Option<String> = None;
-        let mut f_7: Option<Vec<ColumnOrder>> = None;
+        let mut f_7: Option<Vec<Option<ColumnOrder>>> = None;
         let mut f_8: Option<EncryptionAlgorithm> = None;
         let mut f_9: Option<Vec<u8>> = None;
         loop {
@@ -5153,9 +6154,9 @@ impl TSerializable for FileMetaData {
                 },
                 7 => {
                     let list_ident = i_prot.read_list_begin()?;
-                    let mut val: Vec<ColumnOrder> = Vec::with_capacity(list_ident.size as usize);
+                    let mut val: Vec<Option<ColumnOrder>> = Vec::with_capacity(list_ident.size as usize);
                     for _ in 0..list_ident.size {
-                        let list_elem_15 = ColumnOrder::read_from_in_protocol(i_prot)?;
+                        let list_elem_15 = ColumnOrder::read_optional_from_in_protocol(i_prot)?;
                         val.push(list_elem_15);
                     }
                     i_prot.read_list_end()?;
@@ -5234,7 +6235,15 @@ impl TSerializable for FileMetaData {
             o_prot.write_field_begin(&TFieldIdentifier::new("column_orders", TType::List, 7))?;
             o_prot.write_list_begin(&TListIdentifier::new(TType::Struct, fld_var.len() as i32))?;
             for e in fld_var {
-                e.write_to_out_protocol(o_prot)?;
+                // The content of an unknown column order was not kept, so it cannot be written back.
+                e.as_ref().ok_or_else(|| {
+                    thrift::Error::Protocol(
+                        ProtocolError::new(
+                            ProtocolErrorKind::InvalidData,
+                            "cannot write unknown member of union ColumnOrder"
+                        )
+                    )
+                })?.write_to_out_protocol(o_prot)?;
             }
             o_prot.write_list_end()?;
             o_prot.write_field_end()?
@@ -5,6 +5,7 @@ use crate::metadata::thrift_defined::parquet_format::{ColumnChunk, RowGroup};
 use crate::metadata::types::{
     ColumnDescriptorPtr, ColumnPath, SchemaDescriptor, SchemaDescriptorPtr,
 };
+use crate::metadata::version::ApplicationVersion;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct RowGroupMetaData {
@@ -27,12 +28,30 @@ pub struct RowGroupMetaData {
 }
 
 impl RowGroupMetaData {
-    pub(crate) fn from_thrift(schema_desc: &SchemaDescriptor, rg: RowGroup) -> ParquetResult<Self> {
+    pub(crate) fn from_thrift(
+        schema_desc: &SchemaDescriptor,
+        column_orders: Option<&[ColumnOrder]>,
+        version: &ApplicationVersion,
+        rg: RowGroup,
+    ) -> ParquetResult<Self> {
+        if rg.columns.len() != schema_desc.num_columns() {
+            return Err(ParquetError::InvalidFormat(format!(
+                "The row group has {} column chunks for {} leaf columns",
+                rg.columns.len(),
+                schema_desc.num_columns()
+            )));
+        }
         let columns = rg
             .columns
             .into_iter()
             .zip(&schema_desc.leaves)
-            .map(|(cc, column_desc)| ColumnChunkMetaData::from_thrift(column_desc.clone(), cc))
+            .enumerate()
+            .map(|(i, (cc, column_desc))| {
+                let column_order = column_orders
+                    .and_then(|orders| orders.get(i).copied())
+                    .unwrap_or(ColumnOrder::Undefined);
+                ColumnChunkMetaData::from_thrift(column_desc.clone(), column_order, version, cc)
+            })
             .collect::<ParquetResult<_>>()?;
 
         Ok(RowGroupMetaData {
@@ -46,6 +65,103 @@ impl RowGroupMetaData {
     }
 }
 
+/// The number of pages of a column chunk with a page type and encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageEncodingStats {
+    /// The page type (data/dic/...)
+    pub page_type: PageType,
+    /// Encoding of the page
+    pub encoding: Encoding,
+    /// Number of pages of this type with this encoding
+    pub count: usize,
+}
+
+/// The size of the values of a column chunk or page before encoding and compression,
+/// and how often each level occurs, e.g. to plan memory before decoding.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SizeStatistics {
+    /// Bytes of the BYTE_ARRAY values without their length prefix. Only written for
+    /// BYTE_ARRAY columns.
+    pub unencoded_byte_array_data_bytes: Option<usize>,
+    /// Number of values of each repetition level, indexed by level.
+    pub repetition_level_histogram: Option<Vec<usize>>,
+    /// Number of values of each definition level, indexed by level.
+    pub definition_level_histogram: Option<Vec<usize>>,
+}
+
+/// A bounding box of geospatial values, by minimum and maximum coordinate of each axis.
+///
+/// For GEOGRAPHY, `xmin` is greater than `xmax` when the box wraps around the
+/// antimeridian.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub xmin: f64,
+    pub xmax: f64,
+    pub ymin: f64,
+    pub ymax: f64,
+    pub zmin: Option<f64>,
+    pub zmax: Option<f64>,
+    pub mmin: Option<f64>,
+    pub mmax: Option<f64>,
+}
+
+impl BoundingBox {
+    /// Creates a box of the X and Y axes.
+    pub fn new(xmin: f64, xmax: f64, ymin: f64, ymax: f64) -> Self {
+        BoundingBox {
+            xmin,
+            xmax,
+            ymin,
+            ymax,
+            zmin: None,
+            zmax: None,
+            mmin: None,
+            mmax: None,
+        }
+    }
+
+    /// Whether this box and `other` overlap. The Z and M axes are only compared if both
+    /// boxes have them.
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        fn overlap(a: (f64, f64), b: (f64, f64)) -> bool {
+            a.0 <= b.1 && b.0 <= a.1
+        }
+        fn optional_overlap(a: (Option<f64>, Option<f64>), b: (Option<f64>, Option<f64>)) -> bool {
+            match (a, b) {
+                ((Some(amin), Some(amax)), (Some(bmin), Some(bmax))) => {
+                    overlap((amin, amax), (bmin, bmax))
+                }
+                _ => true,
+            }
+        }
+        // A box that wraps around covers [xmin, +inf) and (-inf, xmax].
+        let x = |bbox: &BoundingBox| {
+            if bbox.xmin <= bbox.xmax {
+                vec![(bbox.xmin, bbox.xmax)]
+            } else {
+                vec![(bbox.xmin, f64::INFINITY), (f64::NEG_INFINITY, bbox.xmax)]
+            }
+        };
+        let (x_self, x_other) = (x(self), x(other));
+        x_self
+            .iter()
+            .any(|a| x_other.iter().any(|b| overlap(*a, *b)))
+            && overlap((self.ymin, self.ymax), (other.ymin, other.ymax))
+            && optional_overlap((self.zmin, self.zmax), (other.zmin, other.zmax))
+            && optional_overlap((self.mmin, self.mmax), (other.mmin, other.mmax))
+    }
+}
+
+/// Statistics of the values of a GEOMETRY or GEOGRAPHY column chunk.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct GeospatialStatistics {
+    /// A box containing every value, including the edges of GEOGRAPHY values.
+    pub bbox: Option<BoundingBox>,
+    /// The WKB type codes of the values, e.g. 1 for points and 1003 for polygons with Z.
+    /// `None` if they are not known.
+    pub geospatial_types: Option<Vec<i32>>,
+}
+
 /// Metadata for a column chunk.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ColumnChunkMetaData {
@@ -81,9 +197,14 @@ pub struct ColumnChunkMetaData {
     dictionary_page_offset: Option<usize>,
     /// Optional statistics for this column chunk
     statistics: Option<Statistics>,
-    // Maybe add these later?
-    // encoding_stats: Option<Vec<PageEncodingStats>>,
-    // bloom_filter_offset: Option<i64>,
+    /// How the values of this column are compared, used for min and max
+    sort_order: SortOrder,
+    /// The number of pages of each page type and encoding
+    encoding_stats: Option<Vec<PageEncodingStats>>,
+    bloom_filter_offset: Option<usize>,
+    bloom_filter_length: Option<usize>,
+    size_statistics: Option<SizeStatistics>,
+    geospatial_statistics: Option<GeospatialStatistics>,
     offset_index_offset: Option<usize>,
     offset_index_length: Option<usize>,
     column_index_offset: Option<usize>,
@@ -93,29 +214,58 @@ pub struct ColumnChunkMetaData {
 impl ColumnChunkMetaData {
     pub(crate) fn from_thrift(
         column_descr: ColumnDescriptorPtr,
+        column_order: ColumnOrder,
+        version: &ApplicationVersion,
         cc: ColumnChunk,
     ) -> ParquetResult<Self> {
         if let Some(metatada) = cc.meta_data {
+            let column_type = metatada.type_.try_into()?;
+            let sort_order = ColumnOrder::get_sort_order(
+                column_descr.logical_type(),
+                column_descr.converted_type(),
+                column_type,
+            );
+            let is_float16 = column_descr.logical_type() == Some(&LogicalType::Float16);
+            let mut statistics = match metatada.statistics {
+                Some(stats) if is_float16 => Some(Statistics::from_thrift_float16(stats)?),
+                Some(stats) => Statistics::from_thrift(column_type, stats)?,
+                None => None,
+            };
+            if let Some(statistics) = statistics.as_mut() {
+                statistics.drop_untrusted_min_max(column_order, sort_order, version);
+            }
             Ok(ColumnChunkMetaData {
-                column_type: metatada.type_.try_into()?,
+                column_type,
                 path_in_schema: metatada.path_in_schema,
                 column_descr,
-                encodings: metatada
-                    .encodings
-                    .into_iter()
-                    .map(|en| en.try_into())
-                    .collect::<ParquetResult<Vec<_>>>()?,
+                encodings: metatada.encodings.into_iter().map(|en| en.into()).collect(),
                 file_path: cc.file_path,
                 file_offset: cc.file_offset as _,
                 num_values: metatada.num_values as _,
-                compression: metatada.codec.try_into()?,
-                total_compressed_size: metatada.total_uncompressed_size as _,
+                compression: metatada.codec.into(),
+                total_compressed_size: metatada.total_compressed_size as _,
                 total_uncompressed_size: metatada.total_uncompressed_size as _,
                 data_page_offset: metatada.data_page_offset as _,
                 index_page_offset: metatada.index_page_offset.map(|v| v as _),
                 dictionary_page_offset: metatada.dictionary_page_offset.map(|v| v as _),
-                // TODO!
-                statistics: None,
+                statistics,
+                sort_order,
+                encoding_stats: metatada
+                    .encoding_stats
+                    .map(|stats| {
+                        stats
+                            .into_iter()
+                            .map(|stats| stats.try_into())
+                            .collect::<ParquetResult<Vec<_>>>()
+                    })
+                    .transpose()?,
+                bloom_filter_offset: metatada.bloom_filter_offset.map(|v| v as _),
+                bloom_filter_length: metatada.bloom_filter_length.map(|v| v as _),
+                size_statistics: metatada
+                    .size_statistics
+                    .map(|stats| stats.try_into())
+                    .transpose()?,
+                geospatial_statistics: metatada.geospatial_statistics.map(|stats| stats.into()),
                 offset_index_offset: cc.offset_index_offset.map(|v| v as _),
                 offset_index_length: cc.offset_index_length.map(|v| v as _),
                 column_index_offset: cc.column_index_offset.map(|v| v as _),
@@ -129,6 +279,161 @@ impl ColumnChunkMetaData {
         }
     }
 
+    /// Returns the descriptor of the column this chunk belongs to.
+    pub fn column_descr(&self) -> &ColumnDescriptorPtr {
+        &self.column_descr
+    }
+
+    /// Returns the path of this column in the schema.
+    pub fn path_in_schema(&self) -> &[String] {
+        &self.path_in_schema
+    }
+
+    /// Returns all encodings used for this column chunk.
+    pub fn encodings(&self) -> &[Encoding] {
+        &self.encodings
+    }
+
+    /// Returns the file where the column data is stored, if it isn't the
+    /// file holding the metadata.
+    pub fn file_path(&self) -> Option<&str> {
+        self.file_path.as_deref()
+    }
+
+    /// Returns the byte offset of the `ColumnMetaData` in [`Self::file_path`].
+    pub fn file_offset(&self) -> usize {
+        self.file_offset
+    }
+
+    /// Returns the total number of values in this column chunk.
+    pub fn num_values(&self) -> usize {
+        self.num_values
+    }
+
+    /// Returns the total compressed size of this column chunk, including page headers.
+    pub fn compressed_size(&self) -> usize {
+        self.total_compressed_size
+    }
+
+    /// Returns the total uncompressed size of this column chunk, including page headers.
+    pub fn uncompressed_size(&self) -> usize {
+        self.total_uncompressed_size
+    }
+
+    /// Returns the byte offset of the first data page.
+    pub fn data_page_offset(&self) -> usize {
+        self.data_page_offset
+    }
+
+    /// Returns the byte offset of the root index page, if any.
+    pub fn index_page_offset(&self) -> Option<usize> {
+        self.index_page_offset
+    }
+
+    /// Returns the byte offset of the dictionary page, if any.
+    pub fn dictionary_page_offset(&self) -> Option<usize> {
+        self.dictionary_page_offset
+    }
+
+    /// Returns the statistics of this column chunk, if they were written.
+    pub fn statistics(&self) -> Option<&Statistics> {
+        self.statistics.as_ref()
+    }
+
+    /// Returns how the values of this column are ordered in the statistics.
+    pub fn sort_order(&self) -> SortOrder {
+        self.sort_order
+    }
+
+    /// Returns the number of pages of each page type and encoding, if the writer wrote them.
+    pub fn encoding_stats(&self) -> Option<&[PageEncodingStats]> {
+        self.encoding_stats.as_deref()
+    }
+
+    /// Returns the number of pages of type `page_type` encoded with `encoding`, `None` if
+    /// the encoding stats were not written.
+    pub fn page_count(&self, page_type: PageType, encoding: Encoding) -> Option<usize> {
+        self.encoding_stats.as_ref().map(|stats| {
+            stats
+                .iter()
+                .filter(|stats| stats.page_type == page_type && stats.encoding == encoding)
+                .map(|stats| stats.count)
+                .sum()
+        })
+    }
+
+    /// Whether all data pages are dictionary encoded, according to the encoding stats.
+    /// `false` if the writer did not write them, as [`Self::encodings`] does not tell
+    /// whether a writer fell back to another encoding after the dictionary grew too large.
+    pub fn is_dictionary_encoded(&self) -> bool {
+        self.encoding_stats.as_ref().is_some_and(|stats| {
+            stats.iter().all(|stats| {
+                !stats.page_type.is_data_page()
+                    || stats.count == 0
+                    || stats.encoding.is_dictionary()
+            })
+        })
+    }
+
+    /// Returns the byte offset of the bloom filter of this column chunk.
+    pub fn bloom_filter_offset(&self) -> Option<usize> {
+        self.bloom_filter_offset
+    }
+
+    /// Returns the byte length of the bloom filter of this column chunk, including its
+    /// header. Not written before parquet-format 2.10.
+    pub fn bloom_filter_length(&self) -> Option<usize> {
+        self.bloom_filter_length
+    }
+
+    /// Returns the unencoded sizes and level histograms of this column chunk, if the
+    /// writer wrote them.
+    pub fn size_statistics(&self) -> Option<&SizeStatistics> {
+        self.size_statistics.as_ref()
+    }
+
+    /// Returns the bytes of the BYTE_ARRAY values of this column chunk once decoded,
+    /// without their length prefix, if the writer wrote it.
+    pub fn unencoded_byte_array_data_bytes(&self) -> Option<usize> {
+        self.size_statistics
+            .as_ref()
+            .and_then(|stats| stats.unencoded_byte_array_data_bytes)
+    }
+
+    /// Returns the bounding box and the geometry types of a GEOMETRY or GEOGRAPHY column
+    /// chunk, if the writer wrote them.
+    pub fn geospatial_statistics(&self) -> Option<&GeospatialStatistics> {
+        self.geospatial_statistics.as_ref()
+    }
+
+    /// Returns the bounding box of the values of a GEOMETRY or GEOGRAPHY column chunk, if
+    /// the writer wrote it.
+    pub fn bounding_box(&self) -> Option<&BoundingBox> {
+        self.geospatial_statistics
+            .as_ref()
+            .and_then(|stats| stats.bbox.as_ref())
+    }
+
+    /// Returns the byte offset of the `OffsetIndex` of this column chunk.
+    pub fn offset_index_offset(&self) -> Option<usize> {
+        self.offset_index_offset
+    }
+
+    /// Returns the byte length of the `OffsetIndex` of this column chunk.
+    pub fn offset_index_length(&self) -> Option<usize> {
+        self.offset_index_length
+    }
+
+    /// Returns the byte offset of the `ColumnIndex` of this column chunk.
+    pub fn column_index_offset(&self) -> Option<usize> {
+        self.column_index_offset
+    }
+
+    /// Returns the byte length of the `ColumnIndex` of this column chunk.
+    pub fn column_index_length(&self) -> Option<usize> {
+        self.column_index_length
+    }
+
     /// Get the offset and length of the column within the file.
     pub(crate) fn byte_range(&self) -> (usize, usize) {
         let start = self.dictionary_page_offset.unwrap_or(self.data_page_offset);
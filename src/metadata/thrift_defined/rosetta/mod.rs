@@ -2,11 +2,13 @@
 //! Refer to `parquet_format.rs` file to see raw auto-gen definitions.
 
 mod metadata;
+mod page_index;
 
-use super::parquet_format::TimeUnit;
+use super::parquet_format::{EdgeInterpolationAlgorithm, TimeUnit};
 use super::*;
 use crate::metadata::types::ParquetType;
 pub use metadata::*;
+pub use page_index::*;
 
 /// Types supported by Parquet.  These types are intended to be used in combination
 /// with the encodings to control the on disk storage format.
@@ -85,6 +87,8 @@ pub enum ConvertedType {
     /// the number of milliseconds associated with the provided duration.
     /// This duration of time is independent of any particular timezone or date.
     Interval,
+    /// A converted type unknown to this reader, e.g. written by a newer writer.
+    Unknown(i32),
 }
 /// Logical types used by version 2.4.0+ of the Parquet format.
 ///
@@ -118,6 +122,23 @@ pub enum LogicalType {
     Json,
     Bson,
     Uuid,
+    /// A half precision float, stored as a 2 byte little endian FIXED_LEN_BYTE_ARRAY.
+    Float16,
+    /// A semi-structured value, stored as a group of a binary `metadata` and `value`
+    /// with optional shredded `typed_value` columns, see [`crate::variant`].
+    Variant {
+        specification_version: Option<i8>,
+    },
+    /// Geospatial features in the Well-Known Binary (WKB) format, stored as BYTE_ARRAY,
+    /// with planar edges. The coordinate reference system defaults to OGC:CRS84.
+    Geometry {
+        crs: Option<String>,
+    },
+    /// Like [`LogicalType::Geometry`], with edges on the sphere, interpolated by `algorithm`.
+    Geography {
+        crs: Option<String>,
+        algorithm: EdgeInterpolationAlgorithm,
+    },
 }
 
 /// Representation of field types in schema.
@@ -186,14 +207,31 @@ pub enum Encoding {
     /// This itself does not reduce the size of the data but can lead to better compression
     /// afterwards.
     ByteStreamSplit,
+    /// An encoding unknown to this reader. Pages encoded with it cannot be decoded.
+    Unknown(i32),
+}
+
+impl Encoding {
+    /// Whether values are encoded as indices into the dictionary page.
+    pub fn is_dictionary(&self) -> bool {
+        matches!(self, Self::PlainDictionary | Self::RLE_Dictionary)
+    }
 }
 
-#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PageType {
     DataPageV1,
     IndexPage,
     DictionaryPage,
     DataPageV2,
+    /// A page type unknown to this reader, which skips such pages.
+    Unknown(i32),
+}
+
+impl PageType {
+    pub fn is_data_page(&self) -> bool {
+        matches!(self, Self::DataPageV1 | Self::DataPageV2)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -206,6 +244,8 @@ pub enum Compression {
     Lz4,
     Zstd,
     Lz4Raw,
+    /// A codec unknown to this reader. Its pages cannot be decompressed.
+    Unknown(i32),
 }
 
 /// Sort order for page and column statistics.
@@ -271,6 +311,11 @@ impl ColumnOrder {
                 LogicalType::Timestamp { .. } => SortOrder::Signed,
                 LogicalType::Unknown => SortOrder::Undefined,
                 LogicalType::Uuid => SortOrder::Unsigned,
+                LogicalType::Float16 => SortOrder::Signed,
+                LogicalType::Variant { .. } => SortOrder::Undefined,
+                LogicalType::Geometry { .. } | LogicalType::Geography { .. } => {
+                    SortOrder::Undefined
+                }
             },
             // Fall back to converted type
             None => Self::get_converted_sort_order(converted_type, physical_type),
@@ -311,7 +356,7 @@ impl ColumnOrder {
                     | ConvertedType::TimeStampMillis
                     | ConvertedType::TimeStampMicros => SortOrder::Signed,
 
-                    ConvertedType::Interval => SortOrder::Undefined,
+                    ConvertedType::Interval | ConvertedType::Unknown(_) => SortOrder::Undefined,
 
                     ConvertedType::List | ConvertedType::Map | ConvertedType::MapKeyValue => {
                         SortOrder::Undefined
@@ -0,0 +1,446 @@
+use super::*;
+use crate::errors::{ParquetError, ParquetResult};
+use crate::metadata::statistics::{
+    decode_bool, decode_float16, decode_int96, decode_primitive, normalize_float_bounds,
+    Statistics, ValueStatistics,
+};
+use crate::metadata::version::ApplicationVersion;
+use crate::physical::{ByteArray, FixedLenByteArray, Int96};
+use std::ops::Neg;
+
+/// Whether the min and max values of the pages in a [`ColumnIndex`] are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryOrder {
+    Unordered,
+    Ascending,
+    Descending,
+}
+
+/// The statistics of a single page in a [`ColumnIndex`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageIndex<T> {
+    /// Lower bound of the values in the page, `None` for pages with only nulls or when
+    /// unknown, e.g. a NaN bound of floating point values.
+    pub min: Option<T>,
+    /// Upper bound of the values in the page, `None` for pages with only nulls or when
+    /// unknown.
+    pub max: Option<T>,
+    /// Number of nulls in the page, if it was written.
+    pub null_count: Option<usize>,
+    /// Number of NaN values in a page of floating point values, if it was written.
+    pub nan_count: Option<usize>,
+    /// Whether the page only contains nulls.
+    pub null_page: bool,
+    /// Number of values of each repetition level in the page, if it was written.
+    pub repetition_level_histogram: Option<Vec<usize>>,
+    /// Number of values of each definition level in the page, if it was written.
+    pub definition_level_histogram: Option<Vec<usize>>,
+}
+
+impl<T> PageIndex<T> {
+    /// Whether the page only contains nulls.
+    pub fn is_null_page(&self) -> bool {
+        self.null_page
+    }
+}
+
+/// The page statistics of a column chunk of physical type `T`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NativeColumnIndex<T> {
+    /// The statistics of each page, in page order.
+    pub indexes: Vec<PageIndex<T>>,
+    pub boundary_order: BoundaryOrder,
+}
+
+impl<T: Clone> NativeColumnIndex<T> {
+    fn page_statistics(&self, page: usize) -> ValueStatistics<T> {
+        let index = &self.indexes[page];
+        ValueStatistics::new_inexact(index.min.clone(), index.max.clone(), index.null_count)
+            .with_nan_count(index.nan_count)
+    }
+}
+
+impl<T: PartialEq> NativeColumnIndex<T> {
+    /// Drops the bounds of the pages for which `trusted`, given whether min equals max,
+    /// is false.
+    fn drop_untrusted_bounds(mut self, trusted: &dyn Fn(bool) -> bool) -> Self {
+        for index in &mut self.indexes {
+            let min_equals_max = index.min.is_some() && index.min == index.max;
+            if !trusted(min_equals_max) {
+                index.min = None;
+                index.max = None;
+            }
+        }
+        self
+    }
+}
+
+impl<T: Copy + Into<f64> + Neg<Output = T>> NativeColumnIndex<T> {
+    /// Applies the rules for the bounds of floating point values, see
+    /// [`normalize_float_bounds`].
+    fn normalize_float_bounds(mut self) -> Self {
+        for index in &mut self.indexes {
+            normalize_float_bounds(&mut index.min, &mut index.max);
+        }
+        self
+    }
+}
+
+impl<T> NativeColumnIndex<T> {
+    fn from_thrift(
+        index: TColumnIndex,
+        decode: impl Fn(&[u8]) -> ParquetResult<T>,
+    ) -> ParquetResult<Self> {
+        let num_pages = index.null_pages.len();
+        if index.min_values.len() != num_pages
+            || index.max_values.len() != num_pages
+            || index
+                .null_counts
+                .as_ref()
+                .is_some_and(|counts| counts.len() != num_pages)
+            || index
+                .nan_counts
+                .as_ref()
+                .is_some_and(|counts| counts.len() != num_pages)
+        {
+            return Err(ParquetError::InvalidFormat(
+                "Lists in the column index should have the same length".into(),
+            ));
+        }
+
+        let mut null_counts = index.null_counts.map(|counts| counts.into_iter());
+        let mut nan_counts = index.nan_counts.map(|counts| counts.into_iter());
+        let mut repetition_level_histograms =
+            split_histograms(index.repetition_level_histograms, num_pages)?;
+        let mut definition_level_histograms =
+            split_histograms(index.definition_level_histograms, num_pages)?;
+        let indexes = index
+            .null_pages
+            .into_iter()
+            .zip(index.min_values)
+            .zip(index.max_values)
+            .map(|((is_null_page, min), max)| {
+                let null_count = null_counts
+                    .as_mut()
+                    .and_then(|counts| counts.next())
+                    .map(|count| {
+                        count.try_into().map_err(|_| {
+                            ParquetError::InvalidFormat(
+                                "Negative 'null_count' found in column index".into(),
+                            )
+                        })
+                    })
+                    .transpose()?;
+                let nan_count = nan_counts
+                    .as_mut()
+                    .and_then(|counts| counts.next())
+                    .map(|count| {
+                        count.try_into().map_err(|_| {
+                            ParquetError::InvalidFormat(
+                                "Negative 'nan_count' found in column index".into(),
+                            )
+                        })
+                    })
+                    .transpose()?;
+                let (min, max) = if is_null_page {
+                    (None, None)
+                } else {
+                    (Some(decode(&min)?), Some(decode(&max)?))
+                };
+                Ok(PageIndex {
+                    min,
+                    max,
+                    null_count,
+                    nan_count,
+                    null_page: is_null_page,
+                    repetition_level_histogram: repetition_level_histograms
+                        .as_mut()
+                        .and_then(|histograms| histograms.next()),
+                    definition_level_histogram: definition_level_histograms
+                        .as_mut()
+                        .and_then(|histograms| histograms.next()),
+                })
+            })
+            .collect::<ParquetResult<Vec<_>>>()?;
+
+        Ok(NativeColumnIndex {
+            indexes,
+            boundary_order: index.boundary_order.try_into()?,
+        })
+    }
+}
+
+/// Splits the level histograms of all pages, which are concatenated in the column index,
+/// into one histogram per page.
+fn split_histograms(
+    histograms: Option<Vec<i64>>,
+    num_pages: usize,
+) -> ParquetResult<Option<std::vec::IntoIter<Vec<usize>>>> {
+    let Some(histograms) = histograms else {
+        return Ok(None);
+    };
+    let page_len = histograms.len().checked_div(num_pages).unwrap_or(0);
+    if page_len * num_pages != histograms.len() {
+        return Err(ParquetError::InvalidFormat(format!(
+            "Level histograms of length {} cannot be split in {num_pages} pages",
+            histograms.len()
+        )));
+    }
+    if page_len == 0 {
+        return Ok(None);
+    }
+    let counts = histograms
+        .into_iter()
+        .map(|count| {
+            count.try_into().map_err(|_| {
+                ParquetError::InvalidFormat("Negative level count found in column index".into())
+            })
+        })
+        .collect::<ParquetResult<Vec<usize>>>()?;
+    let pages = counts
+        .chunks(page_len)
+        .map(|histogram| histogram.to_vec())
+        .collect::<Vec<_>>();
+    Ok(Some(pages.into_iter()))
+}
+
+/// The `ColumnIndex` of a column chunk: min, max and null count of every page.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnIndex {
+    Boolean(NativeColumnIndex<bool>),
+    Int32(NativeColumnIndex<i32>),
+    Int64(NativeColumnIndex<i64>),
+    Int96(NativeColumnIndex<Int96>),
+    Float(NativeColumnIndex<f32>),
+    Double(NativeColumnIndex<f64>),
+    ByteArray(NativeColumnIndex<ByteArray>),
+    FixedLenByteArray(NativeColumnIndex<FixedLenByteArray>),
+}
+
+impl ColumnIndex {
+    /// Reads the column index of `column`. The bounds of FLOAT16 columns are decoded to
+    /// `f32`, like their statistics.
+    ///
+    /// Like the `min_value` and `max_value` of column chunks, the bounds of the pages are
+    /// dropped without a known `column_order`, or if the writer is known to have written
+    /// wrong ones, see [`Statistics::drop_untrusted_min_max`].
+    pub(crate) fn from_thrift(
+        column: &ColumnChunkMetaData,
+        column_order: ColumnOrder,
+        version: &ApplicationVersion,
+        index: TColumnIndex,
+    ) -> ParquetResult<Self> {
+        let sort_order = column.sort_order();
+        let trusted = |min_equals_max| {
+            matches!(column_order, ColumnOrder::TypeDefinedOrder(_))
+                && version.has_correct_statistics(column.column_type, sort_order, min_equals_max)
+        };
+        if column.column_descr().logical_type() == Some(&LogicalType::Float16) {
+            let index = NativeColumnIndex::from_thrift(index, decode_float16)?;
+            let index = ColumnIndex::Float(index.normalize_float_bounds());
+            return Ok(index.drop_untrusted_bounds(&trusted));
+        }
+        let out = match column.column_type {
+            PhysicalType::Boolean => {
+                ColumnIndex::Boolean(NativeColumnIndex::from_thrift(index, decode_bool)?)
+            }
+            PhysicalType::Int32 => ColumnIndex::Int32(NativeColumnIndex::from_thrift(
+                index,
+                decode_primitive::<i32>,
+            )?),
+            PhysicalType::Int64 => ColumnIndex::Int64(NativeColumnIndex::from_thrift(
+                index,
+                decode_primitive::<i64>,
+            )?),
+            PhysicalType::Int96 => {
+                ColumnIndex::Int96(NativeColumnIndex::from_thrift(index, decode_int96)?)
+            }
+            PhysicalType::Float => ColumnIndex::Float(
+                NativeColumnIndex::from_thrift(index, decode_primitive::<f32>)?
+                    .normalize_float_bounds(),
+            ),
+            PhysicalType::Double => ColumnIndex::Double(
+                NativeColumnIndex::from_thrift(index, decode_primitive::<f64>)?
+                    .normalize_float_bounds(),
+            ),
+            PhysicalType::ByteArray => {
+                ColumnIndex::ByteArray(NativeColumnIndex::from_thrift(index, |data| {
+                    Ok(ByteArray::new(data.to_vec()))
+                })?)
+            }
+            PhysicalType::FixedLenByteArray => {
+                ColumnIndex::FixedLenByteArray(NativeColumnIndex::from_thrift(index, |data| {
+                    Ok(FixedLenByteArray::new(data.to_vec()))
+                })?)
+            }
+        };
+        Ok(out.drop_untrusted_bounds(&trusted))
+    }
+
+    /// Drops the bounds of the pages for which `trusted`, given whether min equals max,
+    /// is false.
+    fn drop_untrusted_bounds(self, trusted: &dyn Fn(bool) -> bool) -> Self {
+        match self {
+            ColumnIndex::Boolean(index) => {
+                ColumnIndex::Boolean(index.drop_untrusted_bounds(trusted))
+            }
+            ColumnIndex::Int32(index) => ColumnIndex::Int32(index.drop_untrusted_bounds(trusted)),
+            ColumnIndex::Int64(index) => ColumnIndex::Int64(index.drop_untrusted_bounds(trusted)),
+            ColumnIndex::Int96(index) => ColumnIndex::Int96(index.drop_untrusted_bounds(trusted)),
+            ColumnIndex::Float(index) => ColumnIndex::Float(index.drop_untrusted_bounds(trusted)),
+            ColumnIndex::Double(index) => ColumnIndex::Double(index.drop_untrusted_bounds(trusted)),
+            ColumnIndex::ByteArray(index) => {
+                ColumnIndex::ByteArray(index.drop_untrusted_bounds(trusted))
+            }
+            ColumnIndex::FixedLenByteArray(index) => {
+                ColumnIndex::FixedLenByteArray(index.drop_untrusted_bounds(trusted))
+            }
+        }
+    }
+
+    /// Returns the number of pages in the column chunk.
+    pub fn num_pages(&self) -> usize {
+        match self {
+            ColumnIndex::Boolean(index) => index.indexes.len(),
+            ColumnIndex::Int32(index) => index.indexes.len(),
+            ColumnIndex::Int64(index) => index.indexes.len(),
+            ColumnIndex::Int96(index) => index.indexes.len(),
+            ColumnIndex::Float(index) => index.indexes.len(),
+            ColumnIndex::Double(index) => index.indexes.len(),
+            ColumnIndex::ByteArray(index) => index.indexes.len(),
+            ColumnIndex::FixedLenByteArray(index) => index.indexes.len(),
+        }
+    }
+
+    /// Returns the statistics of a page, so that it can be pruned like a column chunk.
+    /// The min and max values are bounds, they may not be exact.
+    pub fn page_statistics(&self, page: usize) -> Statistics {
+        match self {
+            ColumnIndex::Boolean(index) => Statistics::Boolean(index.page_statistics(page)),
+            ColumnIndex::Int32(index) => Statistics::Int32(index.page_statistics(page)),
+            ColumnIndex::Int64(index) => Statistics::Int64(index.page_statistics(page)),
+            ColumnIndex::Int96(index) => Statistics::Int96(index.page_statistics(page)),
+            ColumnIndex::Float(index) => Statistics::Float(index.page_statistics(page)),
+            ColumnIndex::Double(index) => Statistics::Double(index.page_statistics(page)),
+            ColumnIndex::ByteArray(index) => Statistics::ByteArray(index.page_statistics(page)),
+            ColumnIndex::FixedLenByteArray(index) => {
+                Statistics::FixedLenByteArray(index.page_statistics(page))
+            }
+        }
+    }
+}
+
+/// Location of a page in the file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageLocation {
+    /// Offset of the page in the file.
+    pub offset: usize,
+    /// Size of the page, including the header.
+    pub compressed_page_size: usize,
+    /// Index within the row group of the first row of the page.
+    pub first_row_index: usize,
+}
+
+/// The `OffsetIndex` of a column chunk: the location of every data page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetIndex {
+    /// Locations of the data pages, ordered by offset.
+    pub page_locations: Vec<PageLocation>,
+    /// Bytes of the BYTE_ARRAY values of each data page without their length prefix, if
+    /// it was written.
+    pub unencoded_byte_array_data_bytes: Option<Vec<usize>>,
+}
+
+impl OffsetIndex {
+    pub(crate) fn from_thrift(index: TOffsetIndex) -> ParquetResult<Self> {
+        let page_locations = index
+            .page_locations
+            .into_iter()
+            .map(|location| {
+                let invalid =
+                    || ParquetError::InvalidFormat("Negative value found in offset index".into());
+                Ok(PageLocation {
+                    offset: location.offset.try_into().map_err(|_| invalid())?,
+                    compressed_page_size: location
+                        .compressed_page_size
+                        .try_into()
+                        .map_err(|_| invalid())?,
+                    first_row_index: location.first_row_index.try_into().map_err(|_| invalid())?,
+                })
+            })
+            .collect::<ParquetResult<Vec<_>>>()?;
+        if page_locations
+            .windows(2)
+            .any(|pair| pair[1].first_row_index < pair[0].first_row_index)
+        {
+            return Err(ParquetError::InvalidFormat(
+                "The first row indexes of the pages in the offset index should not decrease".into(),
+            ));
+        }
+        let unencoded_byte_array_data_bytes = index
+            .unencoded_byte_array_data_bytes
+            .map(|bytes| {
+                if bytes.len() != page_locations.len() {
+                    return Err(ParquetError::InvalidFormat(
+                        "Lists in the offset index should have the same length".into(),
+                    ));
+                }
+                bytes
+                    .into_iter()
+                    .map(|bytes| {
+                        bytes.try_into().map_err(|_| {
+                            ParquetError::InvalidFormat(
+                                "Negative value found in offset index".into(),
+                            )
+                        })
+                    })
+                    .collect::<ParquetResult<Vec<_>>>()
+            })
+            .transpose()?;
+        Ok(OffsetIndex {
+            page_locations,
+            unencoded_byte_array_data_bytes,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::metadata::{get_metadata, read_page_index, ColumnIndex};
+    use crate::test_util::{write_file, TestColumn, TestValues};
+
+    #[test]
+    fn test_size_statistics() {
+        let pages = vec![
+            vec![Some(b"a".to_vec()), None, Some(b"bc".to_vec())],
+            vec![Some(b"cde".to_vec()), None, None],
+        ];
+        let file = write_file(vec![TestColumn::new(
+            "a",
+            true,
+            TestValues::ByteArray(pages),
+        )]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let chunk = &metadata.row_groups[0].columns[0];
+        assert_eq!(chunk.unencoded_byte_array_data_bytes(), Some(6));
+        let size_statistics = chunk.size_statistics().unwrap();
+        assert_eq!(size_statistics.definition_level_histogram, Some(vec![3, 3]));
+
+        let (column_index, offset_index) =
+            read_page_index(file.as_slice(), &metadata, &[0], &[0]).unwrap();
+        let offset_index = offset_index[0][0].as_ref().unwrap();
+        assert_eq!(
+            offset_index.unencoded_byte_array_data_bytes,
+            Some(vec![3, 3])
+        );
+        let Some(ColumnIndex::ByteArray(column_index)) = &column_index[0][0] else {
+            panic!("expected a byte array column index");
+        };
+        let histograms = column_index
+            .indexes
+            .iter()
+            .map(|page| page.definition_level_histogram.clone().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(histograms, vec![vec![1, 2], vec![2, 1]]);
+    }
+}
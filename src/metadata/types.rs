@@ -1,7 +1,10 @@
 use super::thrift_defined::rosetta::*;
 use super::thrift_defined::*;
 use crate::errors::{ParquetError, ParquetResult};
-use std::rc::Rc;
+use std::collections::HashMap;
+use std::fmt::{self, Write};
+use std::ops::Range;
+use std::str::FromStr;
 use std::sync::Arc;
 
 /// Basic type info. This contains information such as the name of the type,
@@ -69,6 +72,121 @@ pub struct ColumnPath {
     pub(crate) parts: Vec<String>,
 }
 
+impl ColumnPath {
+    pub fn new(parts: Vec<String>) -> Self {
+        ColumnPath { parts }
+    }
+
+    /// Returns the names of the fields from the root to the leaf.
+    pub fn parts(&self) -> &[String] {
+        &self.parts
+    }
+
+    /// Whether the first parts of this path are the parts of `prefix`.
+    pub fn starts_with(&self, prefix: &ColumnPath) -> bool {
+        self.parts.starts_with(&prefix.parts)
+    }
+}
+
+/// Writes the names separated by dots. Dots and backslashes in names are escaped with a
+/// backslash, so that the output can be parsed back.
+impl fmt::Display for ColumnPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, part) in self.parts.iter().enumerate() {
+            if i > 0 {
+                f.write_char('.')?;
+            }
+            for c in part.chars() {
+                if c == '.' || c == '\\' {
+                    f.write_char('\\')?;
+                }
+                f.write_char(c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses names separated by dots, e.g. `a.b.c`. A backslash escapes the next character,
+/// e.g. `a\.b` is the single name `a.b`.
+impl FromStr for ColumnPath {
+    type Err = ParquetError;
+
+    fn from_str(s: &str) -> ParquetResult<Self> {
+        if s.is_empty() {
+            return Err(ParquetError::InvalidFormat("Empty column path".into()));
+        }
+        let mut parts = vec![];
+        let mut part = String::new();
+        let mut chars = s.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '.' => parts.push(std::mem::take(&mut part)),
+                '\\' => part.push(chars.next().ok_or_else(|| {
+                    ParquetError::InvalidFormat(format!("Column path '{s}' ends with an escape"))
+                })?),
+                c => part.push(c),
+            }
+        }
+        parts.push(part);
+        Ok(ColumnPath { parts })
+    }
+}
+
+/// The leaf columns to read, selected by the paths of fields at any level: the path of a
+/// group selects all leaves below it, so that a sub-field of a struct can be read without
+/// its siblings.
+///
+/// The leaf indices from [`Self::leaves`] can be passed to
+/// [`FileReader::with_columns`](crate::FileReader::with_columns).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectionMask {
+    paths: Vec<ColumnPath>,
+}
+
+impl ProjectionMask {
+    pub fn new(paths: Vec<ColumnPath>) -> Self {
+        ProjectionMask { paths }
+    }
+
+    /// Parses every path with [`ColumnPath::from_str`].
+    pub fn parse<'a>(paths: impl IntoIterator<Item = &'a str>) -> ParquetResult<Self> {
+        let paths = paths
+            .into_iter()
+            .map(ColumnPath::from_str)
+            .collect::<ParquetResult<_>>()?;
+        Ok(ProjectionMask { paths })
+    }
+
+    pub fn paths(&self) -> &[ColumnPath] {
+        &self.paths
+    }
+
+    /// Returns the indexes of the selected leaf columns of `schema`, in schema order.
+    ///
+    /// Fails if a path is not a field of the schema.
+    pub fn leaves(&self, schema: &SchemaDescriptor) -> ParquetResult<Vec<usize>> {
+        if let Some(path) = self.paths.iter().find(|path| {
+            !schema
+                .columns()
+                .iter()
+                .any(|column| column.path().starts_with(path))
+        }) {
+            return Err(ParquetError::InvalidFormat(format!(
+                "No field at column path '{path}'"
+            )));
+        }
+        Ok((0..schema.num_columns())
+            .filter(|i| {
+                let column = schema.column(*i);
+                self.paths
+                    .iter()
+                    .any(|path| column.path().starts_with(path))
+            })
+            .collect())
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ColumnDescriptor {
     // The "leaf" primitive type of this column
@@ -80,7 +198,7 @@ pub struct ColumnDescriptor {
     // The path of this column. For instance, "a.b.c.d".
     path: ColumnPath,
 }
-pub type ColumnDescriptorPtr = Rc<ColumnDescriptor>;
+pub type ColumnDescriptorPtr = Arc<ColumnDescriptor>;
 
 impl ColumnDescriptor {
     pub fn converted_type(&self) -> Option<&ConvertedType> {
@@ -100,6 +218,26 @@ impl ColumnDescriptor {
             _ => unreachable!(),
         }
     }
+
+    /// Returns the length of a `FIXED_LEN_BYTE_ARRAY` value, -1 for other types.
+    pub fn type_length(&self) -> i32 {
+        match self.primitive_type {
+            ParquetType::Primitive { type_length, .. } => type_length,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn max_def_level(&self) -> i16 {
+        self.max_def_level
+    }
+
+    pub fn max_rep_level(&self) -> i16 {
+        self.max_rep_level
+    }
+
+    pub fn path(&self) -> &ColumnPath {
+        &self.path
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -108,7 +246,7 @@ pub struct SchemaDescriptor {
     /// This must be a [`ParquetType::Group`] where each field is field is a root column
     /// type in the schema.
     pub(crate) schema: ParquetType,
-    pub(crate) leaves: Vec<Rc<ColumnDescriptor>>,
+    pub(crate) leaves: Vec<Arc<ColumnDescriptor>>,
     // Mapping from a leaf column's index to the root column index that it
     // comes from. For instance: the leaf `a.b.c.d` would have a link back to `a`:
     // -- a  <-----+
@@ -116,8 +254,10 @@ pub struct SchemaDescriptor {
     // -- -- -- c  |
     // -- -- -- -- d
     pub(crate) leaf_to_base: Vec<usize>,
+    // The leaves of every field with a field id, the first field wins if ids repeat.
+    field_ids: HashMap<i32, Range<usize>>,
 }
-pub type SchemaDescriptorPtr = Rc<SchemaDescriptor>;
+pub type SchemaDescriptorPtr = Arc<SchemaDescriptor>;
 
 impl SchemaDescriptor {
     pub(crate) fn new(schema_root: ParquetType) -> SchemaDescriptor {
@@ -136,10 +276,325 @@ impl SchemaDescriptor {
                 &mut vec![],
             )
         }
+        let mut field_ids = HashMap::new();
+        let mut next_leaf = 0;
+        for field in schema_root.fields() {
+            collect_field_ids(field, &mut next_leaf, &mut field_ids);
+        }
         SchemaDescriptor {
             schema: schema_root,
             leaves,
             leaf_to_base,
+            field_ids,
+        }
+    }
+
+    /// Returns the number of leaf columns.
+    pub fn num_columns(&self) -> usize {
+        self.leaves.len()
+    }
+
+    /// Returns the leaf columns, in schema order.
+    pub fn columns(&self) -> &[ColumnDescriptorPtr] {
+        &self.leaves
+    }
+
+    /// Returns the leaf column at index `i`.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn column(&self, i: usize) -> &ColumnDescriptorPtr {
+        &self.leaves[i]
+    }
+
+    /// Returns the top level fields of the schema.
+    pub fn root_fields(&self) -> &[ParquetType] {
+        self.schema.fields()
+    }
+
+    /// Returns the index of the leaf column at `path`.
+    pub fn index_of(&self, path: &ColumnPath) -> Option<usize> {
+        self.leaves.iter().position(|column| column.path() == path)
+    }
+
+    /// Returns the index of the top level field named `name`.
+    pub fn root_index_of(&self, name: &str) -> Option<usize> {
+        self.root_fields()
+            .iter()
+            .position(|field| field.info().name == name)
+    }
+
+    /// Returns the index of the top level field that leaf column `leaf` belongs to.
+    ///
+    /// Panics if `leaf` is out of bounds.
+    pub fn root_of(&self, leaf: usize) -> usize {
+        self.leaf_to_base[leaf]
+    }
+
+    /// Returns the indexes of the leaf columns of top level field `root`, empty if the
+    /// field is a group without leaves.
+    pub fn leaves_of(&self, root: usize) -> Range<usize> {
+        let start = self.leaf_to_base.partition_point(|base| *base < root);
+        let end = self.leaf_to_base.partition_point(|base| *base <= root);
+        start..end
+    }
+
+    /// Returns the index of the leaf column with field id `id`.
+    pub fn index_of_field_id(&self, id: i32) -> Option<usize> {
+        // The range of an empty group is empty, and may start after the last leaf.
+        self.field_ids
+            .get(&id)
+            .filter(|leaves| leaves.len() == 1)
+            .map(|leaves| leaves.start)
+            .filter(|leaf| {
+                self.leaves
+                    .get(*leaf)
+                    .is_some_and(|column| column.primitive_type.info().id == Some(id))
+            })
+    }
+
+    /// Returns the indexes of the leaf columns of the field, at any level, with field id
+    /// `id`. The leaves of a group are next to each other in schema order.
+    pub fn leaves_of_field_id(&self, id: i32) -> Option<Range<usize>> {
+        self.field_ids.get(&id).cloned()
+    }
+}
+
+impl SchemaDescriptor {
+    /// Returns the leaf columns of every group annotated with the VARIANT logical type,
+    /// in schema order.
+    pub fn variant_columns(&self) -> ParquetResult<Vec<VariantColumns>> {
+        let mut variants = vec![];
+        let mut next_leaf = 0;
+        for field in self.schema.fields() {
+            find_variants(field, &mut vec![], (0, 0), &mut next_leaf, &mut variants)?;
+        }
+        Ok(variants)
+    }
+}
+
+/// The leaf columns of a group annotated with the VARIANT logical type: the binary
+/// `metadata`, and the binary `value` and the `typed_value` it may be shredded into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VariantColumns {
+    pub path: ColumnPath,
+    /// The definition level at which the variant is not null.
+    pub def_level: i16,
+    /// The repetition level of the variant, 0 unless it is nested in a list.
+    pub rep_level: i16,
+    /// Index of the leaf column of the `metadata`.
+    pub metadata: usize,
+    pub value: ShreddedColumns,
+}
+
+impl VariantColumns {
+    /// Returns the indexes of all the leaf columns of the variant, which are contiguous.
+    pub fn leaves(&self) -> Range<usize> {
+        let value = self.value.leaves();
+        value.start.min(self.metadata)..value.end.max(self.metadata + 1)
+    }
+}
+
+/// The leaf columns of a shredded variant value: a binary `value` for the values that do
+/// not fit the shredded type, a `typed_value` for the values that do, or both.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShreddedColumns {
+    /// Index of the leaf column of the binary `value`.
+    pub value: Option<usize>,
+    pub typed_value: Option<TypedValueColumns>,
+}
+
+impl ShreddedColumns {
+    /// Returns the indexes of the leaf columns of the value, which are contiguous.
+    pub fn leaves(&self) -> Range<usize> {
+        match (self.value, &self.typed_value) {
+            (Some(value), Some(typed_value)) => {
+                let typed_value = typed_value.leaves();
+                typed_value.start.min(value)..typed_value.end.max(value + 1)
+            }
+            (Some(value), None) => value..value + 1,
+            (None, Some(typed_value)) => typed_value.leaves(),
+            (None, None) => unreachable!("a shredded value has at least one column"),
+        }
+    }
+}
+
+/// The columns of the `typed_value` of a shredded variant value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypedValueColumns {
+    /// Index of the leaf column of a primitive value.
+    Primitive(usize),
+    /// An object shredded into one group per field.
+    Object {
+        /// The definition level at which the object is not null.
+        def_level: i16,
+        fields: Vec<(String, ShreddedColumns)>,
+    },
+    /// An array shredded into a list of elements.
+    Array {
+        /// The definition level at which the array is not null.
+        def_level: i16,
+        /// The repetition level of the elements.
+        rep_level: i16,
+        element: Box<ShreddedColumns>,
+    },
+}
+
+impl TypedValueColumns {
+    /// Returns the indexes of the leaf columns of the value, which are contiguous.
+    pub fn leaves(&self) -> Range<usize> {
+        match self {
+            Self::Primitive(leaf) => *leaf..*leaf + 1,
+            Self::Object { fields, .. } => {
+                fields[0].1.leaves().start..fields[fields.len() - 1].1.leaves().end
+            }
+            Self::Array { element, .. } => element.leaves(),
+        }
+    }
+}
+
+/// Returns the definition and repetition levels of `tp`, given the ones of its parent.
+fn levels(tp: &ParquetType, (def_level, rep_level): (i16, i16)) -> (i16, i16) {
+    match tp.info().repetition {
+        Some(Repetition::Optional) => (def_level + 1, rep_level),
+        Some(Repetition::Repeated) => (def_level + 1, rep_level + 1),
+        _ => (def_level, rep_level),
+    }
+}
+
+fn find_variants<'a>(
+    tp: &'a ParquetType,
+    path: &mut Vec<&'a str>,
+    levels_so_far: (i16, i16),
+    next_leaf: &mut usize,
+    variants: &mut Vec<VariantColumns>,
+) -> ParquetResult<()> {
+    let (def_level, rep_level) = levels(tp, levels_so_far);
+    path.push(&tp.info().name);
+    match tp {
+        ParquetType::Primitive { .. } => *next_leaf += 1,
+        ParquetType::Group { info, fields }
+            if matches!(info.logical_type, Some(LogicalType::Variant { .. })) =>
+        {
+            let (metadata, value) =
+                shredded_columns(fields, (def_level, rep_level), next_leaf, true)?;
+            let metadata = metadata.ok_or_else(|| {
+                ParquetError::InvalidFormat(format!(
+                    "The variant group '{}' has no metadata field",
+                    path.join(".")
+                ))
+            })?;
+            variants.push(VariantColumns {
+                path: ColumnPath::new(path.iter().map(|s| s.to_string()).collect()),
+                def_level,
+                rep_level,
+                metadata,
+                value,
+            });
+        }
+        ParquetType::Group { fields, .. } => {
+            for field in fields {
+                find_variants(field, path, (def_level, rep_level), next_leaf, variants)?;
+            }
+        }
+    }
+    path.pop();
+    Ok(())
+}
+
+/// Returns the columns of the `value` and `typed_value` fields of a shredded value, and of
+/// the `metadata` field if `with_metadata`, i.e. for the group of the variant itself.
+fn shredded_columns(
+    fields: &[ParquetType],
+    levels_so_far: (i16, i16),
+    next_leaf: &mut usize,
+    with_metadata: bool,
+) -> ParquetResult<(Option<usize>, ShreddedColumns)> {
+    let mut metadata = None;
+    let mut value = None;
+    let mut typed_value = None;
+    for field in fields {
+        match (field.info().name.as_str(), field) {
+            ("metadata", ParquetType::Primitive { .. }) if with_metadata => {
+                metadata = Some(*next_leaf);
+                *next_leaf += 1;
+            }
+            ("value", ParquetType::Primitive { .. }) => {
+                value = Some(*next_leaf);
+                *next_leaf += 1;
+            }
+            ("typed_value", _) => {
+                typed_value = Some(typed_value_columns(field, levels_so_far, next_leaf)?);
+            }
+            (name, _) => {
+                return Err(ParquetError::InvalidFormat(format!(
+                    "Unexpected field '{name}' in a variant group"
+                )))
+            }
+        }
+    }
+    if value.is_none() && typed_value.is_none() {
+        return Err(ParquetError::InvalidFormat(
+            "A variant group has neither a value nor a typed_value field".into(),
+        ));
+    }
+    Ok((metadata, ShreddedColumns { value, typed_value }))
+}
+
+fn typed_value_columns(
+    tp: &ParquetType,
+    levels_so_far: (i16, i16),
+    next_leaf: &mut usize,
+) -> ParquetResult<TypedValueColumns> {
+    let (def_level, rep_level) = levels(tp, levels_so_far);
+    let is_list = tp.info().logical_type == Some(LogicalType::List)
+        || tp.info().converted_type == Some(ConvertedType::List);
+    match tp {
+        ParquetType::Primitive { .. } => {
+            *next_leaf += 1;
+            Ok(TypedValueColumns::Primitive(*next_leaf - 1))
+        }
+        ParquetType::Group { fields, .. } if is_list => {
+            let invalid = || {
+                ParquetError::InvalidFormat(
+                    "A shredded variant array must be a list of element groups".into(),
+                )
+            };
+            let [list @ ParquetType::Group { fields, .. }] = fields.as_slice() else {
+                return Err(invalid());
+            };
+            let [element @ ParquetType::Group { fields, .. }] = fields.as_slice() else {
+                return Err(invalid());
+            };
+            let list_levels = levels(list, (def_level, rep_level));
+            let element_levels = levels(element, list_levels);
+            let (_, element) = shredded_columns(fields, element_levels, next_leaf, false)?;
+            Ok(TypedValueColumns::Array {
+                def_level,
+                rep_level: list_levels.1,
+                element: Box::new(element),
+            })
+        }
+        ParquetType::Group { fields, .. } => {
+            if fields.is_empty() {
+                return Err(ParquetError::InvalidFormat(
+                    "A shredded variant object has no fields".into(),
+                ));
+            }
+            let fields = fields
+                .iter()
+                .map(|field| {
+                    let ParquetType::Group { info, fields } = field else {
+                        return Err(ParquetError::InvalidFormat(format!(
+                            "The shredded variant field '{}' is not a group",
+                            field.info().name
+                        )));
+                    };
+                    let field_levels = levels(field, (def_level, rep_level));
+                    let (_, columns) = shredded_columns(fields, field_levels, next_leaf, false)?;
+                    Ok((info.name.clone(), columns))
+                })
+                .collect::<ParquetResult<_>>()?;
+            Ok(TypedValueColumns::Object { def_level, fields })
         }
     }
 }
@@ -172,12 +627,13 @@ fn build_tree<'a>(
                 .iter()
                 .map(|s| s.to_string())
                 .collect::<Vec<_>>();
-            leaves.push(Rc::new(ColumnDescriptor {
+            leaves.push(Arc::new(ColumnDescriptor {
                 primitive_type: tp.clone(),
                 max_def_level,
                 max_rep_level,
                 path: ColumnPath { parts: path },
-            }))
+            }));
+            leaf_to_base.push(root_idx);
         }
         Group { fields, .. } => {
             for field in fields {
@@ -196,6 +652,25 @@ fn build_tree<'a>(
     }
 }
 
+fn collect_field_ids(
+    tp: &ParquetType,
+    next_leaf: &mut usize,
+    field_ids: &mut HashMap<i32, Range<usize>>,
+) {
+    let start = *next_leaf;
+    match tp {
+        ParquetType::Primitive { .. } => *next_leaf += 1,
+        ParquetType::Group { fields, .. } => {
+            for field in fields {
+                collect_field_ids(field, next_leaf, field_ids);
+            }
+        }
+    }
+    if let Some(id) = tp.info().id {
+        field_ids.entry(id).or_insert(start..*next_leaf);
+    }
+}
+
 pub(crate) fn from_thrift(elements: &[SchemaElement]) -> ParquetResult<ParquetType> {
     let (index, parquet_type) = from_thrift_helper(elements, 0)?;
     if index != elements.len() {
@@ -289,3 +764,95 @@ fn from_thrift_helper(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, id: i32, fields: Option<Vec<ParquetType>>) -> ParquetType {
+        let info = TypeInfo {
+            name: name.into(),
+            repetition: Some(Repetition::Optional),
+            converted_type: None,
+            logical_type: None,
+            id: Some(id),
+        };
+        match fields {
+            Some(fields) => ParquetType::Group { info, fields },
+            None => ParquetType::Primitive {
+                info,
+                physical: PhysicalType::Int32,
+                type_length: -1,
+                scale: -1,
+                precision: -1,
+            },
+        }
+    }
+
+    /// a: int, b: {c: int, d: {e: int}}, f: int
+    fn schema() -> SchemaDescriptor {
+        let d = field("d", 4, Some(vec![field("e", 5, None)]));
+        let b = field("b", 2, Some(vec![field("c", 3, None), d]));
+        let fields = vec![field("a", 1, None), b, field("f", 6, None)];
+        SchemaDescriptor::new(ParquetType::Group {
+            info: TypeInfo {
+                name: "schema".into(),
+                repetition: Some(Repetition::Required),
+                converted_type: None,
+                logical_type: None,
+                id: None,
+            },
+            fields,
+        })
+    }
+
+    #[test]
+    fn test_lookup() {
+        let schema = schema();
+
+        assert_eq!(schema.num_columns(), 4);
+        let path = |parts: &[&str]| ColumnPath::new(parts.iter().map(|p| p.to_string()).collect());
+        assert_eq!(schema.index_of(&path(&["b", "d", "e"])), Some(2));
+        assert_eq!(schema.index_of(&path(&["b", "d"])), None);
+        assert_eq!(schema.column(2).path(), &path(&["b", "d", "e"]));
+        assert_eq!(schema.root_index_of("f"), Some(2));
+
+        assert_eq!(schema.leaf_to_base, vec![0, 1, 1, 2]);
+        assert_eq!(schema.root_of(2), 1);
+        assert_eq!(schema.leaves_of(1), 1..3);
+        assert_eq!(schema.leaves_of(2), 3..4);
+
+        assert_eq!(schema.index_of_field_id(5), Some(2));
+        assert_eq!(schema.index_of_field_id(4), None);
+        assert_eq!(schema.leaves_of_field_id(4), Some(2..3));
+        assert_eq!(schema.leaves_of_field_id(2), Some(1..3));
+        assert_eq!(schema.leaves_of_field_id(7), None);
+
+        // An empty group at the end of the schema.
+        let ParquetType::Group { info, mut fields } = schema.schema.clone() else {
+            unreachable!()
+        };
+        fields.push(field("g", 8, Some(vec![])));
+        let schema = SchemaDescriptor::new(ParquetType::Group { info, fields });
+        assert_eq!(schema.index_of_field_id(8), None);
+        assert_eq!(schema.leaves_of_field_id(8), Some(4..4));
+    }
+
+    #[test]
+    fn test_parse_column_path() {
+        let path = ColumnPath::from_str(r"a.b\.c.d\\").unwrap();
+        assert_eq!(path.parts(), ["a", "b.c", "d\\"]);
+        assert_eq!(path.to_string(), r"a.b\.c.d\\");
+        assert!(ColumnPath::from_str(r"a.b\").is_err());
+    }
+
+    #[test]
+    fn test_projection_mask() {
+        let schema = schema();
+        let leaves = |paths: &[&str]| ProjectionMask::parse(paths.iter().copied())?.leaves(&schema);
+        assert_eq!(leaves(&["b.d"]).unwrap(), vec![2]);
+        assert_eq!(leaves(&["f", "b"]).unwrap(), vec![1, 2, 3]);
+        assert_eq!(leaves(&["b.c", "a"]).unwrap(), vec![0, 1]);
+        assert!(leaves(&["b.x"]).is_err());
+    }
+}
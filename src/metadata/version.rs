@@ -0,0 +1,157 @@
+use crate::metadata::{PhysicalType, SortOrder};
+
+/// The application that wrote a Parquet file, parsed from the `created_by` field.
+///
+/// Some writers are known to have written incorrect statistics, this is used to decide
+/// whether statistics can be trusted.
+///
+/// `created_by` is formatted as `<application> version <version> (build <build hash>)`,
+/// e.g. `parquet-mr version 1.8.0 (build 0fda28af84b9746396014ad6a415b90592a98b3b)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplicationVersion {
+    /// `None` if `created_by` was not set.
+    application: Option<String>,
+    /// `(major, minor, patch)`, `None` if it could not be parsed.
+    version: Option<(u32, u32, u32)>,
+}
+
+impl ApplicationVersion {
+    pub fn parse(created_by: Option<&str>) -> Self {
+        let created_by = match created_by {
+            Some(created_by) => created_by.trim(),
+            None => {
+                return ApplicationVersion {
+                    application: None,
+                    version: None,
+                }
+            }
+        };
+
+        let (application, version) = match created_by.split_once(" version ") {
+            Some((application, rest)) => (application, rest.split_whitespace().next()),
+            None => (created_by, None),
+        };
+
+        ApplicationVersion {
+            application: Some(application.trim().to_lowercase()),
+            version: version.and_then(parse_semver),
+        }
+    }
+
+    pub fn application(&self) -> Option<&str> {
+        self.application.as_deref()
+    }
+
+    pub fn version(&self) -> Option<(u32, u32, u32)> {
+        self.version
+    }
+
+    /// Whether this is `application` with a version lower than `version`.
+    /// An unparsable version is assumed to be lower.
+    fn is_before(&self, application: &str, version: (u32, u32, u32)) -> bool {
+        self.application.as_deref() == Some(application)
+            && self.version.is_none_or(|v| v < version)
+    }
+
+    /// Whether the statistics of a column written by this application can be trusted.
+    ///
+    /// This follows the rules of parquet-mr and parquet-cpp:
+    /// * parquet-cpp before 1.3.0 and parquet-mr before 1.10.0 computed min/max with signed
+    ///   comparison for every type, so they are only correct for signed sort orders,
+    ///   or when min equals max.
+    /// * [PARQUET-251](https://issues.apache.org/jira/browse/PARQUET-251): parquet-mr before
+    ///   1.8.0 wrote incorrect binary statistics.
+    /// * Statistics of columns with an undefined sort order are meaningless.
+    pub fn has_correct_statistics(
+        &self,
+        physical_type: PhysicalType,
+        sort_order: SortOrder,
+        min_equals_max: bool,
+    ) -> bool {
+        let is_binary = matches!(
+            physical_type,
+            PhysicalType::ByteArray | PhysicalType::FixedLenByteArray
+        );
+
+        if self.is_before("parquet-cpp", (1, 3, 0)) || self.is_before("parquet-mr", (1, 10, 0)) {
+            if !sort_order.is_signed() && !min_equals_max {
+                return false;
+            }
+            if !is_binary {
+                return true;
+            }
+        }
+
+        // `created_by` was not set, which could have been caused by parquet-mr
+        // during the same time as PARQUET-251, see PARQUET-297.
+        if self.application.is_none() {
+            return true;
+        }
+
+        if sort_order == SortOrder::Undefined {
+            return false;
+        }
+
+        !self.is_before("parquet-mr", (1, 8, 0))
+    }
+}
+
+/// Parses `major.minor.patch` with an optional pre-release or build suffix.
+fn parse_semver(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version.splitn(3, '.').map(|part| {
+        let digits = part
+            .find(|c: char| !c.is_ascii_digit())
+            .map_or(part, |end| &part[..end]);
+        digits.parse::<u32>().ok()
+    });
+    let major = parts.next()??;
+    let minor = parts.next().flatten().unwrap_or(0);
+    let patch = parts.next().flatten().unwrap_or(0);
+    Some((major, minor, patch))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_created_by() {
+        let v = ApplicationVersion::parse(Some(
+            "parquet-mr version 1.8.0 (build 0fda28af84b9746396014ad6a415b90592a98b3b)",
+        ));
+        assert_eq!(v.application(), Some("parquet-mr"));
+        assert_eq!(v.version(), Some((1, 8, 0)));
+
+        let v = ApplicationVersion::parse(Some("parquet-cpp version 1.5.1-SNAPSHOT"));
+        assert_eq!(v.application(), Some("parquet-cpp"));
+        assert_eq!(v.version(), Some((1, 5, 1)));
+
+        let v = ApplicationVersion::parse(Some("parquet-rs"));
+        assert_eq!(v.application(), Some("parquet-rs"));
+        assert_eq!(v.version(), None);
+    }
+
+    #[test]
+    fn test_has_correct_statistics() {
+        use PhysicalType::*;
+        use SortOrder::*;
+
+        let old_mr = ApplicationVersion::parse(Some("parquet-mr version 1.6.0"));
+        assert!(old_mr.has_correct_statistics(Int32, Signed, false));
+        assert!(!old_mr.has_correct_statistics(Int32, Unsigned, false));
+        assert!(old_mr.has_correct_statistics(Int32, Unsigned, true));
+        // PARQUET-251
+        assert!(!old_mr.has_correct_statistics(ByteArray, Signed, false));
+
+        let mr = ApplicationVersion::parse(Some("parquet-mr version 1.9.0"));
+        assert!(mr.has_correct_statistics(ByteArray, Signed, false));
+        assert!(!mr.has_correct_statistics(ByteArray, Unsigned, false));
+
+        let new_mr = ApplicationVersion::parse(Some("parquet-mr version 1.12.3"));
+        assert!(new_mr.has_correct_statistics(ByteArray, Unsigned, false));
+        assert!(!new_mr.has_correct_statistics(Int96, Undefined, false));
+
+        let unknown = ApplicationVersion::parse(None);
+        assert!(unknown.has_correct_statistics(ByteArray, Unsigned, false));
+    }
+}
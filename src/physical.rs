@@ -1,4 +1,6 @@
-use crate::metadata::PhysicalType;
+use crate::metadata::SortOrder;
+use std::cmp::Ordering;
+
 /// A physical native representation of a Parquet fixed-sized type.
 pub trait NativeType: 'static + Copy + Clone {
     type Bytes: AsRef<[u8]> + for<'a> TryFrom<&'a [u8], Error = std::array::TryFromSliceError>;
@@ -23,3 +25,224 @@ native!(i32, PhysicalType::Int32);
 native!(i64, PhysicalType::Int64);
 native!(f32, PhysicalType::Float);
 native!(f64, PhysicalType::Double);
+
+/// The deprecated 96 bit integer, mostly used by legacy writers for nanosecond timestamps.
+/// Stored as three little endian `u32`s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Int96([u32; 3]);
+
+impl Int96 {
+    pub fn new(data: [u32; 3]) -> Self {
+        Int96(data)
+    }
+
+    pub fn data(&self) -> &[u32; 3] {
+        &self.0
+    }
+
+    pub(crate) fn from_le_bytes(bytes: [u8; 12]) -> Self {
+        let word = |i: usize| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap());
+        Int96([word(0), word(1), word(2)])
+    }
+}
+
+/// A variable length byte array, e.g. an utf8 encoded string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ByteArray(Vec<u8>);
+
+impl ByteArray {
+    pub fn new(data: Vec<u8>) -> Self {
+        ByteArray(data)
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for ByteArray {
+    fn from(data: Vec<u8>) -> Self {
+        ByteArray(data)
+    }
+}
+
+/// A byte array of which the length is fixed by the schema.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct FixedLenByteArray(Vec<u8>);
+
+impl FixedLenByteArray {
+    pub fn new(data: Vec<u8>) -> Self {
+        FixedLenByteArray(data)
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the value of the FLOAT16 logical type, a 2 byte little endian half
+    /// precision float. `None` if the value is not 2 bytes long.
+    pub fn as_float16(&self) -> Option<f32> {
+        let bytes = self.0.as_slice().try_into().ok()?;
+        Some(f16_to_f32(u16::from_le_bytes(bytes)))
+    }
+}
+
+/// Converts the bits of a half precision float to `f32`, which represents every half
+/// precision value exactly.
+pub fn f16_to_f32(bits: u16) -> f32 {
+    let sign = ((bits & 0x8000) as u32) << 16;
+    let exponent = ((bits >> 10) & 0x1F) as u32;
+    let mantissa = (bits & 0x3FF) as u32;
+    let magnitude = match exponent {
+        // Zero and subnormals, `mantissa * 2^-24`
+        0 => (mantissa as f32 * f32::from_bits(0x3380_0000)).to_bits(),
+        // Infinities and NaN
+        0x1F => 0x7F80_0000 | (mantissa << 13),
+        _ => ((exponent + 127 - 15) << 23) | (mantissa << 13),
+    };
+    f32::from_bits(sign | magnitude)
+}
+
+impl From<Vec<u8>> for FixedLenByteArray {
+    fn from(data: Vec<u8>) -> Self {
+        FixedLenByteArray(data)
+    }
+}
+
+/// Comparison of physical values under the [`SortOrder`] of their column.
+///
+/// Statistics are aggregated with the sort order of the column, so values should only be
+/// compared against them with the same order.
+pub trait ParquetOrd {
+    /// Compare `self` with `other`. Returns `None` if the order is undefined.
+    fn compare(&self, other: &Self, sort_order: SortOrder) -> Option<Ordering>;
+}
+
+impl ParquetOrd for bool {
+    fn compare(&self, other: &Self, sort_order: SortOrder) -> Option<Ordering> {
+        match sort_order {
+            SortOrder::Undefined => None,
+            _ => Some(self.cmp(other)),
+        }
+    }
+}
+
+macro_rules! integer_ord {
+    ($type:ty, $unsigned:ty) => {
+        impl ParquetOrd for $type {
+            fn compare(&self, other: &Self, sort_order: SortOrder) -> Option<Ordering> {
+                match sort_order {
+                    SortOrder::Signed => Some(self.cmp(other)),
+                    SortOrder::Unsigned => Some((*self as $unsigned).cmp(&(*other as $unsigned))),
+                    SortOrder::Undefined => None,
+                }
+            }
+        }
+    };
+}
+
+integer_ord!(i32, u32);
+integer_ord!(i64, u64);
+
+macro_rules! float_ord {
+    ($type:ty) => {
+        impl ParquetOrd for $type {
+            fn compare(&self, other: &Self, sort_order: SortOrder) -> Option<Ordering> {
+                match sort_order {
+                    SortOrder::Undefined => None,
+                    _ => self.partial_cmp(other),
+                }
+            }
+        }
+    };
+}
+
+float_ord!(f32);
+float_ord!(f64);
+
+impl ParquetOrd for Int96 {
+    fn compare(&self, _other: &Self, _sort_order: SortOrder) -> Option<Ordering> {
+        // The sort order of INT96 is undefined by the spec.
+        None
+    }
+}
+
+/// Compares two byte slices.
+///
+/// * `Unsigned`: lexicographic comparison of the bytes, e.g. for UTF8 strings.
+/// * `Signed`: comparison of the big endian two's complement numbers they represent,
+///   e.g. for decimals.
+fn compare_bytes(left: &[u8], right: &[u8], sort_order: SortOrder) -> Option<Ordering> {
+    match sort_order {
+        SortOrder::Unsigned => Some(left.cmp(right)),
+        SortOrder::Signed => Some(compare_be_signed(left, right)),
+        SortOrder::Undefined => None,
+    }
+}
+
+fn compare_be_signed(left: &[u8], right: &[u8]) -> Ordering {
+    let is_negative = |bytes: &[u8]| bytes.first().is_some_and(|b| (*b as i8) < 0);
+    let (left_neg, right_neg) = (is_negative(left), is_negative(right));
+    if left_neg != right_neg {
+        return if left_neg {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+    }
+
+    // Sign extend the shorter value, so that both have the same width.
+    let pad = if left_neg { 0xFF } else { 0x00 };
+    let width = left.len().max(right.len());
+    let extended = |bytes: &'_ [u8]| {
+        std::iter::repeat_n(pad, width - bytes.len())
+            .chain(bytes.iter().copied())
+            .collect::<Vec<_>>()
+    };
+    extended(left).cmp(&extended(right))
+}
+
+impl ParquetOrd for ByteArray {
+    fn compare(&self, other: &Self, sort_order: SortOrder) -> Option<Ordering> {
+        compare_bytes(self.data(), other.data(), sort_order)
+    }
+}
+
+impl ParquetOrd for FixedLenByteArray {
+    fn compare(&self, other: &Self, sort_order: SortOrder) -> Option<Ordering> {
+        compare_bytes(self.data(), other.data(), sort_order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f16_to_f32() {
+        assert_eq!(f16_to_f32(0x3C00), 1.0);
+        assert_eq!(f16_to_f32(0xC000), -2.0);
+        assert_eq!(f16_to_f32(0x7BFF), 65504.0);
+        assert_eq!(f16_to_f32(0x0001), 2f32.powi(-24));
+        assert!(f16_to_f32(0x8000).is_sign_negative());
+        assert_eq!(f16_to_f32(0xFC00), f32::NEG_INFINITY);
+        assert!(f16_to_f32(0x7E00).is_nan());
+    }
+
+    #[test]
+    fn test_compare_bytes() {
+        let a = ByteArray::new(vec![0x80]); // -128
+        let b = ByteArray::new(vec![0x00, 0x01]); // 1
+        let c = ByteArray::new(vec![0xFF, 0xFF]); // -1
+        assert_eq!(a.compare(&b, SortOrder::Signed), Some(Ordering::Less));
+        assert_eq!(a.compare(&c, SortOrder::Signed), Some(Ordering::Less));
+        assert_eq!(c.compare(&b, SortOrder::Signed), Some(Ordering::Less));
+        assert_eq!(a.compare(&b, SortOrder::Unsigned), Some(Ordering::Greater));
+        assert_eq!(a.compare(&b, SortOrder::Undefined), None);
+
+        assert_eq!(
+            (-1i32).compare(&1, SortOrder::Unsigned),
+            Some(Ordering::Greater)
+        );
+    }
+}
@@ -0,0 +1,1058 @@
+//! Predicates over the columns of a file, evaluated against statistics to skip row groups
+//! that cannot contain matching rows.
+use crate::bloom_filter::{BloomFilterValue, Sbbf};
+use crate::data::{ColumnData, ColumnValues, RowSelection, RowSelector};
+use crate::errors::{ParquetError, ParquetResult};
+use crate::geospatial::wkb_bounding_box;
+use crate::metadata::types::ColumnPath;
+use crate::metadata::{
+    BoundingBox, ColumnChunkMetaData, ColumnIndex, LogicalType, OffsetIndex, PhysicalType,
+    RowGroupMetaData, SortOrder, Statistics, ValueStatistics,
+};
+use crate::physical::{ByteArray, FixedLenByteArray, ParquetOrd};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+
+/// A literal to compare a column with, of the physical type of the column.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScalarValue {
+    Boolean(bool),
+    Int32(i32),
+    Int64(i64),
+    Float(f32),
+    Double(f64),
+    ByteArray(ByteArray),
+    FixedLenByteArray(FixedLenByteArray),
+}
+
+impl From<bool> for ScalarValue {
+    fn from(value: bool) -> Self {
+        ScalarValue::Boolean(value)
+    }
+}
+
+impl From<i32> for ScalarValue {
+    fn from(value: i32) -> Self {
+        ScalarValue::Int32(value)
+    }
+}
+
+impl From<i64> for ScalarValue {
+    fn from(value: i64) -> Self {
+        ScalarValue::Int64(value)
+    }
+}
+
+impl From<f32> for ScalarValue {
+    fn from(value: f32) -> Self {
+        ScalarValue::Float(value)
+    }
+}
+
+impl From<f64> for ScalarValue {
+    fn from(value: f64) -> Self {
+        ScalarValue::Double(value)
+    }
+}
+
+impl From<&str> for ScalarValue {
+    fn from(value: &str) -> Self {
+        ScalarValue::ByteArray(ByteArray::new(value.as_bytes().to_vec()))
+    }
+}
+
+impl ScalarValue {
+    pub fn physical_type(&self) -> PhysicalType {
+        match self {
+            ScalarValue::Boolean(_) => PhysicalType::Boolean,
+            ScalarValue::Int32(_) => PhysicalType::Int32,
+            ScalarValue::Int64(_) => PhysicalType::Int64,
+            ScalarValue::Float(_) => PhysicalType::Float,
+            ScalarValue::Double(_) => PhysicalType::Double,
+            ScalarValue::ByteArray(_) => PhysicalType::ByteArray,
+            ScalarValue::FixedLenByteArray(_) => PhysicalType::FixedLenByteArray,
+        }
+    }
+}
+
+impl BloomFilterValue for ScalarValue {
+    fn bloom_filter_hash(&self) -> u64 {
+        match self {
+            ScalarValue::Boolean(v) => v.bloom_filter_hash(),
+            ScalarValue::Int32(v) => v.bloom_filter_hash(),
+            ScalarValue::Int64(v) => v.bloom_filter_hash(),
+            ScalarValue::Float(v) => v.bloom_filter_hash(),
+            ScalarValue::Double(v) => v.bloom_filter_hash(),
+            ScalarValue::ByteArray(v) => v.bloom_filter_hash(),
+            ScalarValue::FixedLenByteArray(v) => v.bloom_filter_hash(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+/// A boolean expression over the columns of a file.
+///
+/// Comparisons with a null value are null, and a row only matches if the predicate is
+/// `true`, following SQL's three-valued logic.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+    /// `column <op> value`
+    Compare {
+        column: ColumnPath,
+        op: CompareOp,
+        value: ScalarValue,
+    },
+    /// `column IN (values)`
+    In {
+        column: ColumnPath,
+        values: Vec<ScalarValue>,
+    },
+    /// `column BETWEEN low AND high`, both bounds inclusive.
+    Between {
+        column: ColumnPath,
+        low: ScalarValue,
+        high: ScalarValue,
+    },
+    IsNull(ColumnPath),
+    IsNotNull(ColumnPath),
+    /// The bounding box of the value of a GEOMETRY or GEOGRAPHY `column` intersects `bbox`.
+    ///
+    /// Row groups are skipped with the bounding boxes of the column chunks. Rows can only
+    /// be filtered on GEOMETRY columns.
+    Intersects {
+        column: ColumnPath,
+        bbox: BoundingBox,
+    },
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+    Not(Box<Predicate>),
+}
+
+impl Predicate {
+    pub fn compare(column: ColumnPath, op: CompareOp, value: impl Into<ScalarValue>) -> Self {
+        Predicate::Compare {
+            column,
+            op,
+            value: value.into(),
+        }
+    }
+
+    pub fn and(self, other: Predicate) -> Self {
+        Predicate::And(Box::new(self), Box::new(other))
+    }
+
+    pub fn or(self, other: Predicate) -> Self {
+        Predicate::Or(Box::new(self), Box::new(other))
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Self {
+        Predicate::Not(Box::new(self))
+    }
+
+    /// Returns the outcomes this predicate may have for the rows of `row_group`, based on
+    /// the statistics of its column chunks.
+    pub fn evaluate(&self, row_group: &RowGroupMetaData) -> Outcomes {
+        self.evaluate_with_column_filters(row_group, &[], &[])
+    }
+
+    /// Like [`Self::evaluate`], and uses the bloom filters for equality and `IN`.
+    /// `bloom_filters` is indexed by column, columns beyond its end have no filter.
+    pub fn evaluate_with_bloom_filters(
+        &self,
+        row_group: &RowGroupMetaData,
+        bloom_filters: &[Option<Sbbf>],
+    ) -> Outcomes {
+        self.evaluate_with_column_filters(row_group, bloom_filters, &[])
+    }
+
+    /// Like [`Self::evaluate`], and uses the dictionaries of fully dictionary encoded
+    /// column chunks for equality and `IN`. `dictionaries` is indexed by column.
+    ///
+    /// A dictionary must hold every value of its column chunk, so it must only be given
+    /// for column chunks of which all data pages are dictionary encoded.
+    pub fn evaluate_with_dictionaries(
+        &self,
+        row_group: &RowGroupMetaData,
+        dictionaries: &[Option<ColumnValues>],
+    ) -> Outcomes {
+        self.evaluate_with_column_filters(row_group, &[], dictionaries)
+    }
+
+    pub(crate) fn evaluate_with_column_filters(
+        &self,
+        row_group: &RowGroupMetaData,
+        bloom_filters: &[Option<Sbbf>],
+        dictionaries: &[Option<ColumnValues>],
+    ) -> Outcomes {
+        self.evaluate_with(&|path| {
+            let i = column_position(row_group, path)?;
+            Some(ColumnStatistics {
+                bloom_filter: bloom_filters.get(i).and_then(|filter| filter.as_ref()),
+                dictionary: dictionaries
+                    .get(i)
+                    .and_then(|dictionary| dictionary.as_ref()),
+                ..ColumnStatistics::of_column_chunk(&row_group.columns[i])
+            })
+        })
+    }
+
+    /// Returns the rows of `row_group` that may match, based on the statistics of the pages
+    /// in the column indexes. `column_indexes` and `offset_indexes` are indexed by column.
+    /// Columns without both, or nested in a list, are evaluated with the statistics of
+    /// their column chunk.
+    ///
+    /// The pages of different columns may start at different rows, so the predicate is
+    /// evaluated for every run of rows in which no page starts.
+    pub(crate) fn evaluate_pages(
+        &self,
+        row_group: &RowGroupMetaData,
+        column_indexes: &[Option<ColumnIndex>],
+        offset_indexes: &[Option<OffsetIndex>],
+    ) -> ParquetResult<RowSelection> {
+        let num_rows = row_group.num_rows as usize;
+        // The first row and the statistics of every page, per column.
+        let mut pages = vec![None; row_group.columns.len()];
+        for i in self.columns_in(row_group) {
+            let (Some(Some(column_index)), Some(Some(offset_index))) =
+                (column_indexes.get(i), offset_indexes.get(i))
+            else {
+                continue;
+            };
+            let locations = &offset_index.page_locations;
+            if column_index.num_pages() != locations.len() {
+                return Err(ParquetError::InvalidFormat(format!(
+                    "The column index has {} pages, but the offset index has {}",
+                    column_index.num_pages(),
+                    locations.len()
+                )));
+            }
+            if locations.is_empty() || row_group.columns[i].column_descr().max_rep_level() > 0 {
+                continue;
+            }
+            let first_rows = locations
+                .iter()
+                .map(|location| location.first_row_index)
+                .collect::<Vec<_>>();
+            let statistics = (0..locations.len())
+                .map(|page| column_index.page_statistics(page))
+                .collect::<Vec<_>>();
+            pages[i] = Some((first_rows, statistics));
+        }
+
+        let mut starts = pages
+            .iter()
+            .flatten()
+            .flat_map(|(first_rows, _)| first_rows.iter().copied())
+            .filter(|row| *row < num_rows)
+            .chain([0])
+            .collect::<Vec<_>>();
+        starts.sort_unstable();
+        starts.dedup();
+
+        let selectors = starts.iter().enumerate().map(|(run, &start)| {
+            let end = starts.get(run + 1).copied().unwrap_or(num_rows);
+            let outcomes = self.evaluate_with(&|path| {
+                let i = column_position(row_group, path)?;
+                let mut column = ColumnStatistics::of_column_chunk(&row_group.columns[i]);
+                if let Some((first_rows, statistics)) = &pages[i] {
+                    let page = first_rows.partition_point(|row| *row <= start).max(1) - 1;
+                    let page_end = first_rows.get(page + 1).copied().unwrap_or(num_rows);
+                    column.statistics = statistics.get(page);
+                    column.num_values = page_end.saturating_sub(first_rows[page]);
+                }
+                Some(column)
+            });
+            if outcomes.may_be_true {
+                RowSelector::select(end - start)
+            } else {
+                RowSelector::skip(end - start)
+            }
+        });
+        Ok(RowSelection::from_selectors(selectors))
+    }
+
+    /// Whether some rows of `row_group` may match. If not, it can be skipped.
+    pub fn can_match(&self, row_group: &RowGroupMetaData) -> bool {
+        self.evaluate(row_group).may_be_true
+    }
+
+    /// Returns the positions in `row_group` of the columns of which a bloom filter or a
+    /// dictionary can be used to evaluate this predicate.
+    pub(crate) fn equality_columns(&self, row_group: &RowGroupMetaData) -> Vec<usize> {
+        let mut columns = vec![];
+        self.for_each_leaf(&mut |predicate| match predicate {
+            Predicate::Compare {
+                column,
+                op: CompareOp::Eq | CompareOp::NotEq,
+                ..
+            }
+            | Predicate::In { column, .. } => columns.extend(column_position(row_group, column)),
+            _ => {}
+        });
+        columns.sort_unstable();
+        columns.dedup();
+        columns
+    }
+
+    /// Returns the positions in `row_group` of the columns this predicate refers to, skipping
+    /// the columns that are not in the file.
+    pub(crate) fn columns_in(&self, row_group: &RowGroupMetaData) -> Vec<usize> {
+        let mut columns = vec![];
+        self.for_each_leaf(&mut |predicate| {
+            let column = predicate.leaf_column();
+            columns.extend(column.and_then(|path| column_position(row_group, path)));
+        });
+        columns.sort_unstable();
+        columns.dedup();
+        columns
+    }
+
+    /// Returns whether each of the `num_rows` rows matches this predicate. `columns` is
+    /// indexed by column and holds the decoded rows of every column in [`Self::columns_in`].
+    /// A column that is not in the file is null in every row.
+    ///
+    /// Only columns that are not nested in a list are supported.
+    pub(crate) fn evaluate_rows(
+        &self,
+        row_group: &RowGroupMetaData,
+        columns: &[Option<ColumnData>],
+        num_rows: usize,
+    ) -> ParquetResult<Vec<bool>> {
+        let row_values = |path: &ColumnPath| {
+            let Some(i) = column_position(row_group, path) else {
+                return Ok(None);
+            };
+            let data = columns.get(i).and_then(Option::as_ref).ok_or_else(|| {
+                ParquetError::NotSupported(format!(
+                    "Filtering rows on column {:?}, which was not read",
+                    path.parts()
+                ))
+            })?;
+            RowValues::try_new(&row_group.columns[i], data, num_rows).map(Some)
+        };
+        let results = self.evaluate_rows_with(&row_values, num_rows)?;
+        Ok(results
+            .into_iter()
+            .map(|result| result == Some(true))
+            .collect())
+    }
+
+    /// `row_values` returns the rows of a column, `None` if it is not in the file.
+    fn evaluate_rows_with<'a>(
+        &self,
+        row_values: &dyn Fn(&ColumnPath) -> ParquetResult<Option<RowValues<'a>>>,
+        num_rows: usize,
+    ) -> ParquetResult<Vec<Option<bool>>> {
+        let results = match self {
+            Predicate::And(left, right) => zip_with(
+                left.evaluate_rows_with(row_values, num_rows)?,
+                right.evaluate_rows_with(row_values, num_rows)?,
+                and,
+            ),
+            Predicate::Or(left, right) => zip_with(
+                left.evaluate_rows_with(row_values, num_rows)?,
+                right.evaluate_rows_with(row_values, num_rows)?,
+                or,
+            ),
+            Predicate::Not(inner) => inner
+                .evaluate_rows_with(row_values, num_rows)?
+                .into_iter()
+                .map(|result| result.map(|b| !b))
+                .collect(),
+            leaf => {
+                let path = leaf.leaf_column().expect("a leaf predicate has a column");
+                match row_values(path)? {
+                    Some(column) => leaf.evaluate_leaf_rows(&column)?,
+                    None => {
+                        let result = match leaf {
+                            Predicate::IsNull(_) => Some(true),
+                            Predicate::IsNotNull(_) => Some(false),
+                            _ => None,
+                        };
+                        vec![result; num_rows]
+                    }
+                }
+            }
+        };
+        Ok(results)
+    }
+
+    /// Evaluates a predicate that is not `AND`, `OR` or `NOT` for the rows of `column`.
+    fn evaluate_leaf_rows(&self, column: &RowValues) -> ParquetResult<Vec<Option<bool>>> {
+        let results = match self {
+            Predicate::Compare { op, value, .. } => compare_rows(column, *op, value)?,
+            Predicate::In { values, .. } => {
+                let mut results = vec![Some(false); column.rows.len()];
+                for value in values {
+                    let equal = compare_rows(column, CompareOp::Eq, value)?;
+                    results = zip_with(results, equal, or);
+                }
+                results
+            }
+            Predicate::Between { low, high, .. } => zip_with(
+                compare_rows(column, CompareOp::GtEq, low)?,
+                compare_rows(column, CompareOp::LtEq, high)?,
+                and,
+            ),
+            Predicate::IsNull(_) => column.rows.iter().map(|row| Some(row.is_none())).collect(),
+            Predicate::IsNotNull(_) => column.rows.iter().map(|row| Some(row.is_some())).collect(),
+            Predicate::Intersects { bbox, .. } => intersect_rows(column, bbox)?,
+            Predicate::And(..) | Predicate::Or(..) | Predicate::Not(..) => {
+                unreachable!("not a leaf predicate")
+            }
+        };
+        Ok(results)
+    }
+
+    /// Calls `f` with every predicate in this one that is not `AND`, `OR` or `NOT`.
+    fn for_each_leaf<'a>(&'a self, f: &mut dyn FnMut(&'a Predicate)) {
+        match self {
+            Predicate::And(left, right) | Predicate::Or(left, right) => {
+                left.for_each_leaf(f);
+                right.for_each_leaf(f);
+            }
+            Predicate::Not(inner) => inner.for_each_leaf(f),
+            _ => f(self),
+        }
+    }
+
+    fn leaf_column(&self) -> Option<&ColumnPath> {
+        match self {
+            Predicate::Compare { column, .. }
+            | Predicate::In { column, .. }
+            | Predicate::Between { column, .. }
+            | Predicate::IsNull(column)
+            | Predicate::IsNotNull(column)
+            | Predicate::Intersects { column, .. } => Some(column),
+            Predicate::And(..) | Predicate::Or(..) | Predicate::Not(..) => None,
+        }
+    }
+
+    fn evaluate_with<'a>(
+        &self,
+        column_statistics: &dyn Fn(&ColumnPath) -> Option<ColumnStatistics<'a>>,
+    ) -> Outcomes {
+        match self {
+            Predicate::Compare { column, op, value } => {
+                let column = column_statistics(column);
+                let nan_outcome = *op == CompareOp::NotEq;
+                let mut outcomes =
+                    evaluate_values(column.as_ref(), nan_outcome, |stats, sort_order| {
+                        bounds(stats, value, sort_order).map(|bounds| compare(bounds, *op))
+                    });
+                if !may_contain(column.as_ref(), [value]) {
+                    match op {
+                        CompareOp::Eq => outcomes.may_be_true = false,
+                        CompareOp::NotEq => outcomes.may_be_false = false,
+                        _ => {}
+                    }
+                }
+                outcomes
+            }
+            Predicate::In { column, values } => {
+                let column = column_statistics(column);
+                let mut outcomes = evaluate_values(column.as_ref(), false, |stats, sort_order| {
+                    values.iter().try_fold((false, true), |(t, f), value| {
+                        let (may_be_equal, may_differ) =
+                            compare(bounds(stats, value, sort_order)?, CompareOp::Eq);
+                        Some((t || may_be_equal, f && may_differ))
+                    })
+                });
+                if !may_contain(column.as_ref(), values) {
+                    outcomes.may_be_true = false;
+                }
+                outcomes
+            }
+            Predicate::Between { column, low, high } => {
+                let column = column_statistics(column);
+                evaluate_values(column.as_ref(), false, |stats, sort_order| {
+                    let (t_low, f_low) = compare(bounds(stats, low, sort_order)?, CompareOp::GtEq);
+                    let (t_high, f_high) =
+                        compare(bounds(stats, high, sort_order)?, CompareOp::LtEq);
+                    Some((t_low && t_high, f_low || f_high))
+                })
+            }
+            Predicate::IsNull(column) => evaluate_is_null(column_statistics(column)),
+            Predicate::IsNotNull(column) => evaluate_is_null(column_statistics(column)).not(),
+            Predicate::Intersects { column, bbox } => {
+                let column = column_statistics(column);
+                let mut outcomes = evaluate_values(column.as_ref(), false, |_, _| None);
+                let column_bbox = column.and_then(|column| column.bounding_box);
+                if column_bbox.is_some_and(|column_bbox| !column_bbox.intersects(bbox)) {
+                    outcomes.may_be_true = false;
+                }
+                outcomes
+            }
+            Predicate::And(left, right) => left
+                .evaluate_with(column_statistics)
+                .and(right.evaluate_with(column_statistics)),
+            Predicate::Or(left, right) => left
+                .evaluate_with(column_statistics)
+                .or(right.evaluate_with(column_statistics)),
+            Predicate::Not(inner) => inner.evaluate_with(column_statistics).not(),
+        }
+    }
+}
+
+/// Returns the position of the column at `path` in `row_group`.
+fn column_position(row_group: &RowGroupMetaData, path: &ColumnPath) -> Option<usize> {
+    row_group
+        .columns
+        .iter()
+        .position(|column| column.column_descr().path() == path)
+}
+
+/// The decoded values of a column that is not nested in a list, per row.
+struct RowValues<'a> {
+    /// The values, decoded to `f32` for the FLOAT16 logical type.
+    values: Cow<'a, ColumnValues>,
+    /// The position in `values` of the value of every row, `None` for nulls.
+    rows: Vec<Option<usize>>,
+    sort_order: SortOrder,
+    logical_type: Option<LogicalType>,
+}
+
+impl<'a> RowValues<'a> {
+    fn try_new(
+        column: &ColumnChunkMetaData,
+        data: &'a ColumnData,
+        num_rows: usize,
+    ) -> ParquetResult<Self> {
+        let descr = column.column_descr();
+        if descr.max_rep_level() > 0 {
+            return Err(ParquetError::NotSupported(format!(
+                "Filtering rows on column {:?}, which is nested in a list",
+                descr.path().parts()
+            )));
+        }
+        let rows = match &data.def_levels {
+            Some(def_levels) => {
+                let mut position = 0;
+                def_levels
+                    .iter()
+                    .map(|level| {
+                        (*level == descr.max_def_level()).then(|| {
+                            position += 1;
+                            position - 1
+                        })
+                    })
+                    .collect()
+            }
+            None => (0..data.values.len()).map(Some).collect::<Vec<_>>(),
+        };
+        if rows.len() != num_rows {
+            return Err(ParquetError::InvalidFormat(format!(
+                "Expected {num_rows} rows of column {:?}, found {}",
+                descr.path().parts(),
+                rows.len()
+            )));
+        }
+        let values = if descr.logical_type() == Some(&LogicalType::Float16) {
+            Cow::Owned(ColumnValues::Float(data.values.decode_float16()?))
+        } else {
+            Cow::Borrowed(&data.values)
+        };
+        Ok(RowValues {
+            values,
+            rows,
+            sort_order: column.sort_order(),
+            logical_type: descr.logical_type().cloned(),
+        })
+    }
+
+    fn map<T>(&self, values: &[T], f: impl Fn(&T) -> bool) -> Vec<Option<bool>> {
+        self.rows
+            .iter()
+            .map(|row| row.map(|i| f(&values[i])))
+            .collect()
+    }
+}
+
+/// Returns whether the bounding box of the geometry of every row intersects `bbox`, `None`
+/// for nulls.
+fn intersect_rows(column: &RowValues, bbox: &BoundingBox) -> ParquetResult<Vec<Option<bool>>> {
+    // The edges of GEOGRAPHY values are not straight, their vertices do not bound them.
+    let (ColumnValues::ByteArray(values), Some(LogicalType::Geometry { .. })) =
+        (column.values.as_ref(), &column.logical_type)
+    else {
+        return Err(ParquetError::NotSupported(format!(
+            "Filtering rows by bounding box on a {:?} column",
+            column.logical_type
+        )));
+    };
+    column
+        .rows
+        .iter()
+        .map(|row| {
+            row.map(|i| {
+                let value_bbox = wkb_bounding_box(values[i].data())?;
+                Ok(value_bbox.is_some_and(|value_bbox| value_bbox.intersects(bbox)))
+            })
+            .transpose()
+        })
+        .collect()
+}
+
+/// Returns `column <op> value` for every row, `None` for nulls.
+fn compare_rows(
+    column: &RowValues,
+    op: CompareOp,
+    value: &ScalarValue,
+) -> ParquetResult<Vec<Option<bool>>> {
+    let sort_order = column.sort_order;
+    if sort_order == SortOrder::Undefined && !matches!(op, CompareOp::Eq | CompareOp::NotEq) {
+        return Err(ParquetError::NotSupported(format!(
+            "Comparing values of a {:?} column with an undefined sort order",
+            column.values.physical_type()
+        )));
+    }
+    let results = match (column.values.as_ref(), value) {
+        (ColumnValues::Boolean(values), ScalarValue::Boolean(v)) => {
+            column.map(values, |x| compare_value(x, v, op, sort_order))
+        }
+        (ColumnValues::Int32(values), ScalarValue::Int32(v)) => {
+            column.map(values, |x| compare_value(x, v, op, sort_order))
+        }
+        (ColumnValues::Int64(values), ScalarValue::Int64(v)) => {
+            column.map(values, |x| compare_value(x, v, op, sort_order))
+        }
+        (ColumnValues::Float(values), ScalarValue::Float(v)) => {
+            column.map(values, |x| compare_value(x, v, op, sort_order))
+        }
+        (ColumnValues::Double(values), ScalarValue::Double(v)) => {
+            column.map(values, |x| compare_value(x, v, op, sort_order))
+        }
+        (ColumnValues::ByteArray(values), ScalarValue::ByteArray(v)) => {
+            column.map(values, |x| compare_value(x, v, op, sort_order))
+        }
+        (ColumnValues::FixedLenByteArray(values), ScalarValue::FixedLenByteArray(v)) => {
+            column.map(values, |x| compare_value(x, v, op, sort_order))
+        }
+        (values, value) => {
+            return Err(ParquetError::NotSupported(format!(
+                "Comparing a {:?} column with a {:?} value",
+                values.physical_type(),
+                value.physical_type()
+            )))
+        }
+    };
+    Ok(results)
+}
+
+/// Returns `left <op> right`. Comparisons with NaN are false, except `!=`.
+fn compare_value<T: ParquetOrd + PartialEq>(
+    left: &T,
+    right: &T,
+    op: CompareOp,
+    sort_order: SortOrder,
+) -> bool {
+    let ordering = match sort_order {
+        // Values without an order can still be compared for equality.
+        SortOrder::Undefined => (left == right).then_some(Ordering::Equal),
+        _ => left.compare(right, sort_order),
+    };
+    match ordering {
+        Some(ordering) => match op {
+            CompareOp::Eq => ordering.is_eq(),
+            CompareOp::NotEq => ordering.is_ne(),
+            CompareOp::Lt => ordering.is_lt(),
+            CompareOp::LtEq => ordering.is_le(),
+            CompareOp::Gt => ordering.is_gt(),
+            CompareOp::GtEq => ordering.is_ge(),
+        },
+        None => op == CompareOp::NotEq,
+    }
+}
+
+fn zip_with(
+    left: Vec<Option<bool>>,
+    right: Vec<Option<bool>>,
+    f: fn(Option<bool>, Option<bool>) -> Option<bool>,
+) -> Vec<Option<bool>> {
+    left.into_iter().zip(right).map(|(l, r)| f(l, r)).collect()
+}
+
+/// `AND` in three-valued logic, `None` is null.
+fn and(left: Option<bool>, right: Option<bool>) -> Option<bool> {
+    match (left, right) {
+        (Some(false), _) | (_, Some(false)) => Some(false),
+        (Some(true), Some(true)) => Some(true),
+        _ => None,
+    }
+}
+
+/// `OR` in three-valued logic, `None` is null.
+fn or(left: Option<bool>, right: Option<bool>) -> Option<bool> {
+    match (left, right) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None,
+    }
+}
+
+/// The results a predicate may have for a set of rows, in three-valued logic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcomes {
+    pub may_be_true: bool,
+    pub may_be_false: bool,
+    pub may_be_null: bool,
+}
+
+impl Outcomes {
+    /// Nothing is known about the rows.
+    pub const UNKNOWN: Outcomes = Outcomes {
+        may_be_true: true,
+        may_be_false: true,
+        may_be_null: true,
+    };
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn not(self) -> Outcomes {
+        Outcomes {
+            may_be_true: self.may_be_false,
+            may_be_false: self.may_be_true,
+            may_be_null: self.may_be_null,
+        }
+    }
+
+    /// The outcomes of `a AND b`, where `a` and `b` may be any combination of their outcomes.
+    pub fn and(self, other: Outcomes) -> Outcomes {
+        Outcomes {
+            may_be_true: self.may_be_true && other.may_be_true,
+            may_be_false: self.may_be_false || other.may_be_false,
+            may_be_null: (self.may_be_null && (other.may_be_true || other.may_be_null))
+                || (other.may_be_null && self.may_be_true),
+        }
+    }
+
+    /// The outcomes of `a OR b`, where `a` and `b` may be any combination of their outcomes.
+    pub fn or(self, other: Outcomes) -> Outcomes {
+        self.not().and(other.not()).not()
+    }
+}
+
+/// The statistics of a column, for a row group or a page.
+struct ColumnStatistics<'a> {
+    statistics: Option<&'a Statistics>,
+    sort_order: SortOrder,
+    num_values: usize,
+    physical_type: PhysicalType,
+    bloom_filter: Option<&'a Sbbf>,
+    /// Every distinct value of the column.
+    dictionary: Option<&'a ColumnValues>,
+    /// The bounding box of a GEOMETRY or GEOGRAPHY column.
+    bounding_box: Option<&'a BoundingBox>,
+}
+
+impl<'a> ColumnStatistics<'a> {
+    /// The statistics of `column`, without a bloom filter or a dictionary.
+    fn of_column_chunk(column: &'a ColumnChunkMetaData) -> Self {
+        ColumnStatistics {
+            statistics: column.statistics(),
+            sort_order: column.sort_order(),
+            num_values: column.num_values(),
+            physical_type: column.column_type,
+            bloom_filter: None,
+            dictionary: None,
+            bounding_box: column.bounding_box(),
+        }
+    }
+}
+
+/// Whether any of `values` may be in the column. Only `false` if the bloom filter or the
+/// dictionary of the column rules out all of them.
+fn may_contain<'a>(
+    column: Option<&ColumnStatistics>,
+    values: impl IntoIterator<Item = &'a ScalarValue>,
+) -> bool {
+    let Some(column) = column else {
+        return true;
+    };
+    // A value of another physical type is hashed and compared differently.
+    values.into_iter().any(|value| {
+        value.physical_type() != column.physical_type
+            || (column.bloom_filter.is_none_or(|filter| {
+                filter.check(value) || other_zero(value).is_some_and(|zero| filter.check(&zero))
+            }) && column
+                .dictionary
+                .is_none_or(|dictionary| dictionary_contains(dictionary, value)))
+    })
+}
+
+/// Returns `-0.0` for `+0.0` and `+0.0` for `-0.0`, which are equal but hashed
+/// differently.
+fn other_zero(value: &ScalarValue) -> Option<ScalarValue> {
+    match value {
+        ScalarValue::Float(v) if *v == 0.0 => Some(ScalarValue::Float(-v)),
+        ScalarValue::Double(v) if *v == 0.0 => Some(ScalarValue::Double(-v)),
+        _ => None,
+    }
+}
+
+/// Whether `value` is in `dictionary`. `true` if their types differ.
+fn dictionary_contains(dictionary: &ColumnValues, value: &ScalarValue) -> bool {
+    match (dictionary, value) {
+        (ColumnValues::Boolean(d), ScalarValue::Boolean(v)) => d.contains(v),
+        (ColumnValues::Int32(d), ScalarValue::Int32(v)) => d.contains(v),
+        (ColumnValues::Int64(d), ScalarValue::Int64(v)) => d.contains(v),
+        (ColumnValues::Float(d), ScalarValue::Float(v)) => d.contains(v),
+        (ColumnValues::Double(d), ScalarValue::Double(v)) => d.contains(v),
+        (ColumnValues::ByteArray(d), ScalarValue::ByteArray(v)) => d.contains(v),
+        (ColumnValues::FixedLenByteArray(d), ScalarValue::FixedLenByteArray(v)) => d.contains(v),
+        _ => true,
+    }
+}
+
+/// Evaluates a predicate that is null for null values. `evaluate` returns whether the
+/// predicate may be true and whether it may be false for the non-null values, `None`
+/// if that cannot be derived from the statistics.
+///
+/// `min` and `max` of floating point values exclude NaN, so `nan_outcome`, the result
+/// of the predicate for NaN, is a possible outcome unless there are no NaN values.
+fn evaluate_values(
+    column: Option<&ColumnStatistics>,
+    nan_outcome: bool,
+    evaluate: impl Fn(&Statistics, SortOrder) -> Option<(bool, bool)>,
+) -> Outcomes {
+    let Some(ColumnStatistics {
+        statistics: Some(statistics),
+        sort_order,
+        num_values,
+        ..
+    }) = column
+    else {
+        return Outcomes::UNKNOWN;
+    };
+    let (sort_order, num_values) = (*sort_order, *num_values);
+
+    let (may_be_null, all_null) = match statistics.null_count() {
+        Some(null_count) => (null_count > 0, null_count >= num_values),
+        None => (true, false),
+    };
+    let (mut may_be_true, mut may_be_false) = if all_null {
+        (false, false)
+    } else {
+        evaluate(statistics, sort_order).unwrap_or((true, true))
+    };
+    let is_float = matches!(statistics, Statistics::Float(_) | Statistics::Double(_));
+    if is_float && !all_null && statistics.nan_count() != Some(0) {
+        if nan_outcome {
+            may_be_true = true;
+        } else {
+            may_be_false = true;
+        }
+    }
+    Outcomes {
+        may_be_true,
+        may_be_false,
+        may_be_null,
+    }
+}
+
+fn evaluate_is_null(column: Option<ColumnStatistics>) -> Outcomes {
+    let null_count = column.as_ref().and_then(|column| {
+        column
+            .statistics
+            .and_then(|stats| stats.null_count())
+            .map(|null_count| (null_count, column.num_values))
+    });
+    match null_count {
+        Some((null_count, num_values)) => Outcomes {
+            may_be_true: null_count > 0,
+            may_be_false: null_count < num_values,
+            may_be_null: false,
+        },
+        None => Outcomes {
+            may_be_null: false,
+            ..Outcomes::UNKNOWN
+        },
+    }
+}
+
+/// Returns how `min` and `max` compare with `value`. `None` if they are missing, their
+/// type differs from `value`, or the sort order is undefined.
+fn bounds(
+    statistics: &Statistics,
+    value: &ScalarValue,
+    sort_order: SortOrder,
+) -> Option<(Ordering, Ordering)> {
+    fn bounds<T: ParquetOrd>(
+        stats: &ValueStatistics<T>,
+        value: &T,
+        sort_order: SortOrder,
+    ) -> Option<(Ordering, Ordering)> {
+        Some((
+            stats.min()?.compare(value, sort_order)?,
+            stats.max()?.compare(value, sort_order)?,
+        ))
+    }
+
+    match (statistics, value) {
+        (Statistics::Boolean(s), ScalarValue::Boolean(v)) => bounds(s, v, sort_order),
+        (Statistics::Int32(s), ScalarValue::Int32(v)) => bounds(s, v, sort_order),
+        (Statistics::Int64(s), ScalarValue::Int64(v)) => bounds(s, v, sort_order),
+        (Statistics::Float(s), ScalarValue::Float(v)) => bounds(s, v, sort_order),
+        (Statistics::Double(s), ScalarValue::Double(v)) => bounds(s, v, sort_order),
+        (Statistics::ByteArray(s), ScalarValue::ByteArray(v)) => bounds(s, v, sort_order),
+        (Statistics::FixedLenByteArray(s), ScalarValue::FixedLenByteArray(v)) => {
+            bounds(s, v, sort_order)
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether `column <op> value` may be true and whether it may be false, for values
+/// between `min` and `max`, given how they compare with `value`.
+fn compare((min, max): (Ordering, Ordering), op: CompareOp) -> (bool, bool) {
+    use Ordering::*;
+    match op {
+        CompareOp::Eq => (
+            min != Greater && max != Less,
+            !(min == Equal && max == Equal),
+        ),
+        CompareOp::NotEq => (
+            !(min == Equal && max == Equal),
+            min != Greater && max != Less,
+        ),
+        CompareOp::Lt => (min == Less, max != Less),
+        CompareOp::LtEq => (min != Greater, max == Greater),
+        CompareOp::Gt => (max == Greater, min != Greater),
+        CompareOp::GtEq => (max != Less, min == Less),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::{get_metadata, parquet_format};
+    use crate::read_column;
+    use crate::test_util::{write_file, TestColumn, TestValues};
+
+    fn path(name: &str) -> ColumnPath {
+        ColumnPath::new(vec![name.to_string()])
+    }
+
+    #[test]
+    fn test_evaluate_row_group() {
+        // a: 10..20 with a null, b: only nulls
+        let a = vec![(10..20).map(Some).chain([None]).collect()];
+        let b = vec![vec![None::<Vec<u8>>; 11]];
+        let file = write_file(vec![
+            TestColumn::new("a", true, TestValues::Int32(a)),
+            TestColumn::new("b", true, TestValues::ByteArray(b)),
+        ]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let rg = &metadata.row_groups[0];
+
+        let a_gt = |v: i32| Predicate::compare(path("a"), CompareOp::Gt, v);
+        assert!(a_gt(15).can_match(rg));
+        assert!(!a_gt(19).can_match(rg));
+        // Null for the null row, so NOT does not make it match.
+        let outcomes = a_gt(5).not().evaluate(rg);
+        assert!(!outcomes.may_be_true && outcomes.may_be_null);
+
+        let a_in = Predicate::In {
+            column: path("a"),
+            values: vec![1.into(), 25.into()],
+        };
+        assert!(!a_in.can_match(rg));
+        assert!(a_in.clone().or(Predicate::IsNull(path("a"))).can_match(rg));
+
+        // Comparisons with a column of nulls are never true.
+        let b_eq = Predicate::compare(path("b"), CompareOp::Eq, "x");
+        assert!(!b_eq.can_match(rg));
+        assert!(!b_eq.clone().not().can_match(rg));
+        assert!(!Predicate::IsNotNull(path("b")).can_match(rg));
+
+        // A literal of another type cannot be compared, so nothing can be skipped.
+        assert!(Predicate::compare(path("a"), CompareOp::Eq, 100i64).can_match(rg));
+        assert!(Predicate::compare(path("missing"), CompareOp::Eq, 1).can_match(rg));
+    }
+
+    #[test]
+    fn test_evaluate_floats() {
+        let mut zeros = TestColumn::new("zeros", false, TestValues::Double(vec![vec![Some(-0.0)]]));
+        zeros.bloom_filter = true;
+        let file = write_file(vec![
+            zeros,
+            TestColumn::new(
+                "a",
+                false,
+                TestValues::Double(vec![vec![Some(1.0), Some(f64::NAN)]]),
+            ),
+        ]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let rg = &metadata.row_groups[0];
+
+        // A max of -0.0 is read as +0.0, and +0.0 is in a bloom filter of -0.0.
+        let zeros = |op, v: f64| Predicate::compare(path("zeros"), op, v);
+        assert!(zeros(CompareOp::GtEq, 0.0).can_match(rg));
+        assert!(zeros(CompareOp::Eq, 0.0).can_match(rg));
+        assert!(!zeros(CompareOp::Gt, 0.0).can_match(rg));
+
+        // The NaN, which is not in the bounds of `a`, is different from any value.
+        let a = |op, v: f64| Predicate::compare(path("a"), op, v);
+        assert!(a(CompareOp::NotEq, 1.0).can_match(rg));
+        assert!(a(CompareOp::Eq, 1.0).not().can_match(rg));
+        assert!(!a(CompareOp::Gt, 1.0).can_match(rg));
+    }
+
+    #[test]
+    fn test_evaluate_bounding_box() {
+        let point = |x: f64, y: f64| {
+            let mut wkb = vec![1, 1, 0, 0, 0];
+            wkb.extend(x.to_le_bytes());
+            wkb.extend(y.to_le_bytes());
+            Some(wkb)
+        };
+        let values = vec![vec![point(1.0, 1.0), None, point(3.0, 2.0)]];
+        let mut geometry = TestColumn::new("geometry", true, TestValues::ByteArray(values));
+        geometry.logical_type = Some(parquet_format::LogicalType::GEOMETRY(Default::default()));
+        geometry.geospatial_statistics = Some(parquet_format::GeospatialStatistics {
+            bbox: Some(parquet_format::BoundingBox::new(
+                1.0.into(),
+                3.0.into(),
+                1.0.into(),
+                2.0.into(),
+                None,
+                None,
+                None,
+                None,
+            )),
+            geospatial_types: Some(vec![1]),
+        });
+        let file = write_file(vec![geometry]);
+        let metadata = get_metadata(file.as_slice()).unwrap();
+        let rg = &metadata.row_groups[0];
+        assert_eq!(
+            rg.columns[0].bounding_box(),
+            Some(&BoundingBox::new(1.0, 3.0, 1.0, 2.0))
+        );
+
+        let intersects = |bbox| Predicate::Intersects {
+            column: path("geometry"),
+            bbox,
+        };
+        assert!(intersects(BoundingBox::new(2.0, 5.0, 0.0, 1.5)).can_match(rg));
+        assert!(!intersects(BoundingBox::new(4.0, 5.0, 0.0, 1.5)).can_match(rg));
+        // A box that wraps around the antimeridian, from 170 to -170 degrees.
+        assert!(!intersects(BoundingBox::new(170.0, -170.0, 0.0, 5.0)).can_match(rg));
+        assert!(intersects(BoundingBox::new(170.0, 2.0, 0.0, 5.0)).can_match(rg));
+
+        let data = read_column(file.as_slice(), rg, 0, None, None).unwrap();
+        let rows = intersects(BoundingBox::new(2.0, 5.0, 0.0, 5.0))
+            .evaluate_rows(rg, &[Some(data)], 3)
+            .unwrap();
+        assert_eq!(rows, vec![false, false, true]);
+    }
+}
@@ -10,6 +10,10 @@ pub trait ParquetReader: Send + Sync {
     /// Get the length in bytes from the source
     fn len(&self) -> usize;
 
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Get a range as bytes
     /// This should fail if the exact number of bytes cannot be read
     fn get_bytes(&self, start: usize, length: usize) -> ParquetResult<&[u8]>;
@@ -0,0 +1,515 @@
+//! Writes small in-memory Parquet files, so that the readers can be tested without fixtures.
+use crate::bloom_filter::{BloomFilterValue, Sbbf};
+use crate::metadata::thrift_defined::parquet_format::*;
+use crate::physical::f16_to_f32;
+use std::cmp::Ordering;
+use thrift::protocol::{TCompactOutputProtocol, TSerializable};
+
+/// A physical type that can be PLAIN encoded.
+pub(crate) trait TestType: Clone + PartialOrd + BloomFilterValue {
+    const PHYSICAL_TYPE: Type;
+
+    /// PLAIN encoding, as used in data pages.
+    fn encode(&self, out: &mut Vec<u8>);
+
+    /// PLAIN encoding without length prefix, as used in statistics.
+    fn stat_bytes(&self) -> Vec<u8> {
+        let mut out = vec![];
+        self.encode(&mut out);
+        out
+    }
+}
+
+impl TestType for i32 {
+    const PHYSICAL_TYPE: Type = Type::INT32;
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes())
+    }
+}
+
+impl TestType for i64 {
+    const PHYSICAL_TYPE: Type = Type::INT64;
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes())
+    }
+}
+
+impl TestType for f64 {
+    const PHYSICAL_TYPE: Type = Type::DOUBLE;
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes())
+    }
+}
+
+impl TestType for Vec<u8> {
+    const PHYSICAL_TYPE: Type = Type::BYTE_ARRAY;
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&(self.len() as u32).to_le_bytes());
+        out.extend_from_slice(self);
+    }
+
+    fn stat_bytes(&self) -> Vec<u8> {
+        self.clone()
+    }
+}
+
+impl BloomFilterValue for Vec<u8> {
+    fn bloom_filter_hash(&self) -> u64 {
+        self.as_slice().bloom_filter_hash()
+    }
+}
+
+/// The bits of a FLOAT16 value, ordered by the value they represent.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Float16(pub u16);
+
+impl PartialOrd for Float16 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        f16_to_f32(self.0).partial_cmp(&f16_to_f32(other.0))
+    }
+}
+
+impl TestType for Float16 {
+    const PHYSICAL_TYPE: Type = Type::FIXED_LEN_BYTE_ARRAY;
+
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.0.to_le_bytes())
+    }
+}
+
+impl BloomFilterValue for Float16 {
+    fn bloom_filter_hash(&self) -> u64 {
+        self.0.to_le_bytes().as_slice().bloom_filter_hash()
+    }
+}
+
+/// The values of a flat column, split in data pages.
+pub(crate) enum TestValues {
+    Int32(Vec<Vec<Option<i32>>>),
+    Int64(Vec<Vec<Option<i64>>>),
+    Double(Vec<Vec<Option<f64>>>),
+    ByteArray(Vec<Vec<Option<Vec<u8>>>>),
+    /// Written with the FLOAT16 logical type.
+    Float16(Vec<Vec<Option<Float16>>>),
+}
+
+pub(crate) struct TestColumn {
+    pub name: &'static str,
+    pub optional: bool,
+    pub logical_type: Option<LogicalType>,
+    /// Whether to write a bloom filter of the values.
+    pub bloom_filter: bool,
+    /// Whether to write the length of the bloom filter, which is missing before
+    /// parquet-format 2.10.
+    pub bloom_filter_length: bool,
+    /// Whether to dictionary encode the data pages.
+    pub dictionary: bool,
+    pub geospatial_statistics: Option<GeospatialStatistics>,
+    pub values: TestValues,
+}
+
+impl TestColumn {
+    pub(crate) fn new(name: &'static str, optional: bool, values: TestValues) -> Self {
+        TestColumn {
+            name,
+            optional,
+            logical_type: None,
+            bloom_filter: false,
+            bloom_filter_length: true,
+            dictionary: false,
+            geospatial_statistics: None,
+            values,
+        }
+    }
+}
+
+fn serialize(value: &impl TSerializable) -> Vec<u8> {
+    let mut out = vec![];
+    let mut protocol = TCompactOutputProtocol::new(&mut out);
+    value.write_to_out_protocol(&mut protocol).unwrap();
+    out
+}
+
+/// Encodes `values` in RLE runs only, of `bit_width` bits.
+fn encode_rle(values: &[u32], bit_width: u32, out: &mut Vec<u8>) {
+    let num_bytes = bit_width.div_ceil(8) as usize;
+    let mut i = 0;
+    while i < values.len() {
+        let value = values[i];
+        let run = values[i..].iter().take_while(|v| **v == value).count();
+        let mut header = (run as u64) << 1;
+        // ULEB128
+        loop {
+            let byte = (header & 0x7F) as u8;
+            header >>= 7;
+            if header == 0 {
+                out.push(byte);
+                break;
+            }
+            out.push(byte | 0x80);
+        }
+        out.extend_from_slice(&value.to_le_bytes()[..num_bytes]);
+        i += run;
+    }
+}
+
+/// RLE encodes levels of at most 8 bits, with a 4 byte length prefix.
+pub(crate) fn encode_levels(levels: &[u8], out: &mut Vec<u8>) {
+    let levels = levels.iter().map(|l| *l as u32).collect::<Vec<_>>();
+    let mut encoded = vec![];
+    encode_rle(&levels, 8, &mut encoded);
+    out.extend_from_slice(&(encoded.len() as u32).to_le_bytes());
+    out.extend(encoded);
+}
+
+fn encode_def_levels<T>(values: &[Option<T>], out: &mut Vec<u8>) {
+    let levels = values.iter().map(|v| v.is_some() as u8).collect::<Vec<_>>();
+    encode_levels(&levels, out)
+}
+
+fn statistics<'a, T: TestType + 'a>(values: impl Iterator<Item = &'a Option<T>>) -> Statistics {
+    let mut min: Option<&T> = None;
+    let mut max: Option<&T> = None;
+    let mut null_count = 0;
+    for value in values {
+        match value {
+            Some(v) => {
+                if min.is_none_or(|min| v < min) {
+                    min = Some(v);
+                }
+                if max.is_none_or(|max| v > max) {
+                    max = Some(v);
+                }
+            }
+            None => null_count += 1,
+        }
+    }
+    Statistics {
+        max: None,
+        min: None,
+        null_count: Some(null_count),
+        distinct_count: None,
+        max_value: max.map(|v| v.stat_bytes()),
+        min_value: min.map(|v| v.stat_bytes()),
+        is_max_value_exact: Some(true),
+        is_min_value_exact: Some(true),
+        nan_count: None,
+    }
+}
+
+struct WrittenChunk {
+    meta_data: ColumnMetaData,
+    column_index: ColumnIndex,
+    offset_index: OffsetIndex,
+}
+
+fn write_chunk<T: TestType>(
+    column: &TestColumn,
+    pages: &[Vec<Option<T>>],
+    out: &mut Vec<u8>,
+) -> WrittenChunk {
+    let (name, optional) = (column.name, column.optional);
+    let chunk_start = out.len();
+    let mut column_index = ColumnIndex {
+        null_pages: vec![],
+        min_values: vec![],
+        max_values: vec![],
+        boundary_order: BoundaryOrder::UNORDERED,
+        null_counts: Some(vec![]),
+        repetition_level_histograms: None,
+        definition_level_histograms: optional.then(Vec::new),
+        nan_counts: None,
+    };
+    let mut page_locations = vec![];
+    let is_byte_array = T::PHYSICAL_TYPE == Type::BYTE_ARRAY;
+    let mut unencoded_bytes = vec![];
+    let mut first_row_index = 0;
+
+    let mut dictionary: Vec<&T> = vec![];
+    if column.dictionary {
+        for value in pages.iter().flatten().flatten() {
+            if !dictionary.contains(&value) {
+                dictionary.push(value);
+            }
+        }
+        let mut data = vec![];
+        dictionary.iter().for_each(|v| v.encode(&mut data));
+        out.extend(serialize(&PageHeader {
+            type_: PageType::DICTIONARY_PAGE,
+            uncompressed_page_size: data.len() as i32,
+            compressed_page_size: data.len() as i32,
+            crc: None,
+            data_page_header: None,
+            index_page_header: None,
+            dictionary_page_header: Some(DictionaryPageHeader {
+                num_values: dictionary.len() as i32,
+                encoding: Encoding::PLAIN,
+                is_sorted: None,
+            }),
+            data_page_header_v2: None,
+        }));
+        out.extend(data);
+    }
+    let data_page_offset = out.len();
+    let (encoding, encodings) = if column.dictionary {
+        let encodings = vec![Encoding::PLAIN, Encoding::RLE, Encoding::RLE_DICTIONARY];
+        (Encoding::RLE_DICTIONARY, encodings)
+    } else {
+        (Encoding::PLAIN, vec![Encoding::PLAIN, Encoding::RLE])
+    };
+
+    for values in pages {
+        let mut data = vec![];
+        if optional {
+            encode_def_levels(values, &mut data);
+        }
+        if column.dictionary {
+            let bit_width = u32::BITS - (dictionary.len() as u32).leading_zeros();
+            let indices = values
+                .iter()
+                .flatten()
+                .map(|v| dictionary.iter().position(|d| *d == v).unwrap() as u32)
+                .collect::<Vec<_>>();
+            data.push(bit_width as u8);
+            encode_rle(&indices, bit_width, &mut data);
+        } else {
+            values.iter().flatten().for_each(|v| v.encode(&mut data));
+        }
+
+        let stats = statistics(values.iter());
+        let header = PageHeader {
+            type_: PageType::DATA_PAGE,
+            uncompressed_page_size: data.len() as i32,
+            compressed_page_size: data.len() as i32,
+            crc: None,
+            data_page_header: Some(DataPageHeader {
+                num_values: values.len() as i32,
+                encoding,
+                definition_level_encoding: Encoding::RLE,
+                repetition_level_encoding: Encoding::RLE,
+                statistics: Some(stats.clone()),
+            }),
+            index_page_header: None,
+            dictionary_page_header: None,
+            data_page_header_v2: None,
+        };
+
+        let page_start = out.len();
+        out.extend(serialize(&header));
+        out.extend(data);
+        page_locations.push(PageLocation {
+            offset: page_start as i64,
+            compressed_page_size: (out.len() - page_start) as i32,
+            first_row_index,
+        });
+        first_row_index += values.len() as i64;
+
+        column_index.null_pages.push(stats.min_value.is_none());
+        column_index
+            .min_values
+            .push(stats.min_value.unwrap_or_default());
+        column_index
+            .max_values
+            .push(stats.max_value.unwrap_or_default());
+        column_index
+            .null_counts
+            .as_mut()
+            .unwrap()
+            .push(stats.null_count.unwrap());
+        if let Some(histograms) = column_index.definition_level_histograms.as_mut() {
+            let null_count = stats.null_count.unwrap();
+            histograms.extend([null_count, values.len() as i64 - null_count]);
+        }
+        let bytes = values.iter().flatten().map(|v| v.stat_bytes().len());
+        unencoded_bytes.push(bytes.sum::<usize>() as i64);
+    }
+
+    let chunk_size = (out.len() - chunk_start) as i64;
+    let mut encoding_stats = vec![PageEncodingStats {
+        page_type: PageType::DATA_PAGE,
+        encoding,
+        count: pages.len() as i32,
+    }];
+    if column.dictionary {
+        encoding_stats.insert(
+            0,
+            PageEncodingStats {
+                page_type: PageType::DICTIONARY_PAGE,
+                encoding: Encoding::PLAIN,
+                count: 1,
+            },
+        );
+    }
+
+    let bloom_filter_offset = out.len();
+    if column.bloom_filter {
+        let mut sbbf = Sbbf::new(&[0; 128]).unwrap();
+        for value in pages.iter().flatten().flatten() {
+            sbbf.insert_hash(value.bloom_filter_hash());
+        }
+        let bitset = sbbf.to_bytes();
+        out.extend(serialize(&BloomFilterHeader {
+            num_bytes: bitset.len() as i32,
+            algorithm: BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+            hash: BloomFilterHash::XXHASH(XxHash {}),
+            compression: BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+        }));
+        out.extend(bitset);
+    }
+    let bloom_filter_length = out.len() - bloom_filter_offset;
+
+    let definition_level_histogram =
+        column_index
+            .definition_level_histograms
+            .as_ref()
+            .map(|histograms| {
+                let nulls = histograms.iter().step_by(2).sum();
+                vec![nulls, first_row_index - nulls]
+            });
+    let size_statistics = SizeStatistics {
+        unencoded_byte_array_data_bytes: is_byte_array.then(|| unencoded_bytes.iter().sum()),
+        repetition_level_histogram: None,
+        definition_level_histogram,
+    };
+    let meta_data = ColumnMetaData {
+        type_: T::PHYSICAL_TYPE,
+        encodings,
+        path_in_schema: vec![name.to_string()],
+        codec: CompressionCodec::UNCOMPRESSED,
+        num_values: first_row_index,
+        total_uncompressed_size: chunk_size,
+        total_compressed_size: chunk_size,
+        key_value_metadata: None,
+        data_page_offset: data_page_offset as i64,
+        index_page_offset: None,
+        dictionary_page_offset: column.dictionary.then_some(chunk_start as i64),
+        statistics: Some(statistics(pages.iter().flatten())),
+        encoding_stats: Some(encoding_stats),
+        bloom_filter_offset: column.bloom_filter.then_some(bloom_filter_offset as i64),
+        bloom_filter_length: (column.bloom_filter && column.bloom_filter_length)
+            .then_some(bloom_filter_length as i32),
+        size_statistics: Some(size_statistics),
+        geospatial_statistics: column.geospatial_statistics.clone(),
+    };
+    WrittenChunk {
+        meta_data,
+        column_index,
+        offset_index: OffsetIndex {
+            page_locations,
+            unencoded_byte_array_data_bytes: is_byte_array.then_some(unencoded_bytes),
+        },
+    }
+}
+
+/// Writes a file with a single row group. Every column has one data page per entry of its
+/// [`TestValues`], PLAIN or dictionary encoded and uncompressed. The page index is written for all columns.
+pub(crate) fn write_file(columns: Vec<TestColumn>) -> Vec<u8> {
+    write_file_with_column_orders(columns, true)
+}
+
+/// Like [`write_file`], without the column orders of the file if `column_orders` is false,
+/// as written before parquet-format 2.4.0.
+pub(crate) fn write_file_with_column_orders(
+    columns: Vec<TestColumn>,
+    column_orders: bool,
+) -> Vec<u8> {
+    let mut out = b"PAR1".to_vec();
+    let mut schema = vec![SchemaElement {
+        type_: None,
+        type_length: None,
+        repetition_type: None,
+        name: "schema".into(),
+        num_children: Some(columns.len() as i32),
+        converted_type: None,
+        scale: None,
+        precision: None,
+        field_id: None,
+        logical_type: None,
+    }];
+
+    let mut chunks = vec![];
+    for column in &columns {
+        let chunk = match &column.values {
+            TestValues::Int32(pages) => write_chunk(column, pages, &mut out),
+            TestValues::Int64(pages) => write_chunk(column, pages, &mut out),
+            TestValues::Double(pages) => write_chunk(column, pages, &mut out),
+            TestValues::ByteArray(pages) => write_chunk(column, pages, &mut out),
+            TestValues::Float16(pages) => write_chunk(column, pages, &mut out),
+        };
+        let is_float16 = matches!(column.values, TestValues::Float16(_));
+        schema.push(SchemaElement {
+            type_: Some(chunk.meta_data.type_),
+            type_length: is_float16.then_some(2),
+            repetition_type: Some(if column.optional {
+                FieldRepetitionType::OPTIONAL
+            } else {
+                FieldRepetitionType::REQUIRED
+            }),
+            name: column.name.into(),
+            num_children: None,
+            converted_type: None,
+            scale: None,
+            precision: None,
+            field_id: None,
+            logical_type: if is_float16 {
+                Some(LogicalType::FLOAT16(Float16Type {}))
+            } else {
+                column.logical_type.clone()
+            },
+        });
+        chunks.push(chunk);
+    }
+
+    let num_rows = chunks.first().map_or(0, |chunk| chunk.meta_data.num_values);
+    let column_chunks = chunks
+        .into_iter()
+        .map(|chunk| {
+            let column_index_offset = out.len();
+            out.extend(serialize(&chunk.column_index));
+            let offset_index_offset = out.len();
+            out.extend(serialize(&chunk.offset_index));
+            ColumnChunk {
+                file_path: None,
+                file_offset: chunk.meta_data.data_page_offset,
+                meta_data: Some(chunk.meta_data),
+                offset_index_offset: Some(offset_index_offset as i64),
+                offset_index_length: Some((out.len() - offset_index_offset) as i32),
+                column_index_offset: Some(column_index_offset as i64),
+                column_index_length: Some((offset_index_offset - column_index_offset) as i32),
+                crypto_metadata: None,
+                encrypted_column_metadata: None,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let metadata = FileMetaData {
+        version: 2,
+        num_rows,
+        column_orders: column_orders
+            .then(|| vec![Some(ColumnOrder::TYPEORDER(TypeDefinedOrder {})); column_chunks.len()]),
+        row_groups: vec![RowGroup {
+            total_byte_size: 0,
+            num_rows,
+            sorting_columns: None,
+            file_offset: None,
+            total_compressed_size: None,
+            ordinal: Some(0),
+            columns: column_chunks,
+        }],
+        schema,
+        key_value_metadata: None,
+        created_by: Some("polars-parquet tests".into()),
+        encryption_algorithm: None,
+        footer_signing_key_metadata: None,
+    };
+
+    let metadata = serialize(&metadata);
+    out.extend_from_slice(&metadata);
+    out.extend_from_slice(&(metadata.len() as i32).to_le_bytes());
+    out.extend_from_slice(b"PAR1");
+    out
+}
@@ -0,0 +1,715 @@
+//! Decoding of the VARIANT logical type: semi-structured values encoded as a binary
+//! `metadata` (the dictionary of the field names) and a binary `value`, which writers may
+//! shred into `typed_value` columns of a fixed type.
+//!
+//! [`read_variants`] rebuilds the variants of a row group, merging the shredded columns
+//! back into the values.
+use crate::data::{read_column, ColumnData, ColumnValues};
+use crate::errors::{ParquetError, ParquetResult};
+use crate::metadata::parquet_format::TimeUnit;
+use crate::metadata::types::{
+    ColumnDescriptor, SchemaDescriptor, ShreddedColumns, TypedValueColumns, VariantColumns,
+};
+use crate::metadata::{LogicalType, RowGroupMetaData};
+use crate::reader::ParquetReader;
+
+/// A decoded variant value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Variant {
+    Null,
+    Boolean(bool),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Float(f32),
+    Double(f64),
+    Decimal4 {
+        value: i32,
+        scale: u8,
+    },
+    Decimal8 {
+        value: i64,
+        scale: u8,
+    },
+    Decimal16 {
+        value: i128,
+        scale: u8,
+    },
+    /// Days since the Unix epoch.
+    Date(i32),
+    /// Microseconds since the Unix epoch, in UTC.
+    TimestampMicros(i64),
+    /// Microseconds since the Unix epoch, without time zone.
+    TimestampNtzMicros(i64),
+    /// Nanoseconds since the Unix epoch, in UTC.
+    TimestampNanos(i64),
+    /// Nanoseconds since the Unix epoch, without time zone.
+    TimestampNtzNanos(i64),
+    /// Microseconds since midnight, without time zone.
+    TimeNtzMicros(i64),
+    Binary(Vec<u8>),
+    String(String),
+    Uuid([u8; 16]),
+    /// The fields of an object, in the order they are stored.
+    Object(Vec<(String, Variant)>),
+    Array(Vec<Variant>),
+}
+
+impl Variant {
+    /// Decodes a variant from its binary `metadata` and `value`.
+    pub fn decode(metadata: &[u8], value: &[u8]) -> ParquetResult<Self> {
+        decode_value(&decode_metadata(metadata)?, value, 0)
+    }
+}
+
+fn invalid(message: &str) -> ParquetError {
+    ParquetError::InvalidFormat(format!("Invalid variant: {message}"))
+}
+
+fn slice(data: &[u8], start: usize, len: usize) -> ParquetResult<&[u8]> {
+    start
+        .checked_add(len)
+        .and_then(|end| data.get(start..end))
+        .ok_or_else(|| invalid("unexpected end of the data"))
+}
+
+/// Reads a little endian unsigned integer of `size` bytes at `start`.
+fn read_uint(data: &[u8], start: usize, size: usize) -> ParquetResult<usize> {
+    let bytes = slice(data, start, size)?;
+    Ok(bytes
+        .iter()
+        .rev()
+        .fold(0, |acc, byte| (acc << 8) | *byte as usize))
+}
+
+fn read_le<const N: usize>(data: &[u8], start: usize) -> ParquetResult<[u8; N]> {
+    Ok(slice(data, start, N)?.try_into().unwrap())
+}
+
+fn read_str(data: &[u8], start: usize, len: usize) -> ParquetResult<&str> {
+    std::str::from_utf8(slice(data, start, len)?).map_err(|_| invalid("a string is not UTF-8"))
+}
+
+/// Returns the field names of the dictionary of a variant `metadata`.
+fn decode_metadata(data: &[u8]) -> ParquetResult<Vec<&str>> {
+    let header = *data.first().ok_or_else(|| invalid("empty metadata"))?;
+    if header & 0x0F != 1 {
+        return Err(ParquetError::NotSupported(format!(
+            "Variant metadata version {}",
+            header & 0x0F
+        )));
+    }
+    let offset_size = ((header >> 6) & 0x03) as usize + 1;
+    let size = read_uint(data, 1, offset_size)?;
+    let offsets_start = 1 + offset_size;
+    let strings_start = offsets_start + (size + 1) * offset_size;
+    (0..size)
+        .map(|i| {
+            let start = read_uint(data, offsets_start + i * offset_size, offset_size)?;
+            let end = read_uint(data, offsets_start + (i + 1) * offset_size, offset_size)?;
+            let len = end
+                .checked_sub(start)
+                .ok_or_else(|| invalid("decreasing dictionary offsets"))?;
+            read_str(data, strings_start + start, len)
+        })
+        .collect()
+}
+
+/// The maximum nesting depth of objects and arrays, which bounds the recursion of
+/// [`decode_value`] on corrupt or hostile values.
+const MAX_DEPTH: usize = 128;
+
+fn decode_value(dictionary: &[&str], data: &[u8], depth: usize) -> ParquetResult<Variant> {
+    if depth > MAX_DEPTH {
+        return Err(invalid("objects and arrays are nested too deeply"));
+    }
+    let header = *data.first().ok_or_else(|| invalid("empty value"))?;
+    let value_header = header >> 2;
+    match header & 0x03 {
+        0 => decode_primitive(value_header, data),
+        1 => Ok(Variant::String(
+            read_str(data, 1, value_header as usize)?.to_string(),
+        )),
+        2 => {
+            let offset_size = (value_header & 0x03) as usize + 1;
+            let id_size = ((value_header >> 2) & 0x03) as usize + 1;
+            let count_size = if value_header & 0x10 != 0 { 4 } else { 1 };
+            let num_elements = read_uint(data, 1, count_size)?;
+            let ids_start = 1 + count_size;
+            let offsets_start = ids_start + num_elements * id_size;
+            let values_start = offsets_start + (num_elements + 1) * offset_size;
+            let fields = (0..num_elements)
+                .map(|i| {
+                    let id = read_uint(data, ids_start + i * id_size, id_size)?;
+                    let name = dictionary
+                        .get(id)
+                        .ok_or_else(|| invalid("field id out of the dictionary"))?;
+                    let offset = read_uint(data, offsets_start + i * offset_size, offset_size)?;
+                    let value = data
+                        .get(values_start + offset..)
+                        .ok_or_else(|| invalid("unexpected end of the data"))?;
+                    Ok((
+                        name.to_string(),
+                        decode_value(dictionary, value, depth + 1)?,
+                    ))
+                })
+                .collect::<ParquetResult<_>>()?;
+            Ok(Variant::Object(fields))
+        }
+        _ => {
+            let offset_size = (value_header & 0x03) as usize + 1;
+            let count_size = if value_header & 0x04 != 0 { 4 } else { 1 };
+            let num_elements = read_uint(data, 1, count_size)?;
+            let offsets_start = 1 + count_size;
+            let values_start = offsets_start + (num_elements + 1) * offset_size;
+            let elements = (0..num_elements)
+                .map(|i| {
+                    let offset = read_uint(data, offsets_start + i * offset_size, offset_size)?;
+                    let value = data
+                        .get(values_start + offset..)
+                        .ok_or_else(|| invalid("unexpected end of the data"))?;
+                    decode_value(dictionary, value, depth + 1)
+                })
+                .collect::<ParquetResult<_>>()?;
+            Ok(Variant::Array(elements))
+        }
+    }
+}
+
+fn decode_primitive(type_id: u8, data: &[u8]) -> ParquetResult<Variant> {
+    let decimal_scale = || Ok::<_, ParquetError>(read_le::<1>(data, 1)?[0]);
+    Ok(match type_id {
+        0 => Variant::Null,
+        1 => Variant::Boolean(true),
+        2 => Variant::Boolean(false),
+        3 => Variant::Int8(i8::from_le_bytes(read_le(data, 1)?)),
+        4 => Variant::Int16(i16::from_le_bytes(read_le(data, 1)?)),
+        5 => Variant::Int32(i32::from_le_bytes(read_le(data, 1)?)),
+        6 => Variant::Int64(i64::from_le_bytes(read_le(data, 1)?)),
+        7 => Variant::Double(f64::from_le_bytes(read_le(data, 1)?)),
+        8 => Variant::Decimal4 {
+            scale: decimal_scale()?,
+            value: i32::from_le_bytes(read_le(data, 2)?),
+        },
+        9 => Variant::Decimal8 {
+            scale: decimal_scale()?,
+            value: i64::from_le_bytes(read_le(data, 2)?),
+        },
+        10 => Variant::Decimal16 {
+            scale: decimal_scale()?,
+            value: i128::from_le_bytes(read_le(data, 2)?),
+        },
+        11 => Variant::Date(i32::from_le_bytes(read_le(data, 1)?)),
+        12 => Variant::TimestampMicros(i64::from_le_bytes(read_le(data, 1)?)),
+        13 => Variant::TimestampNtzMicros(i64::from_le_bytes(read_le(data, 1)?)),
+        14 => Variant::Float(f32::from_le_bytes(read_le(data, 1)?)),
+        15 => {
+            let len = u32::from_le_bytes(read_le(data, 1)?) as usize;
+            Variant::Binary(slice(data, 5, len)?.to_vec())
+        }
+        16 => {
+            let len = u32::from_le_bytes(read_le(data, 1)?) as usize;
+            Variant::String(read_str(data, 5, len)?.to_string())
+        }
+        17 => Variant::TimeNtzMicros(i64::from_le_bytes(read_le(data, 1)?)),
+        18 => Variant::TimestampNanos(i64::from_le_bytes(read_le(data, 1)?)),
+        19 => Variant::TimestampNtzNanos(i64::from_le_bytes(read_le(data, 1)?)),
+        // UUIDs are stored big endian, like in the UUID logical type.
+        20 => Variant::Uuid(read_le(data, 1)?),
+        _ => {
+            return Err(ParquetError::NotSupported(format!(
+                "Variant primitive type {type_id}"
+            )))
+        }
+    })
+}
+
+/// Reads the leaf columns of the variant `columns` in a row group and rebuilds its values,
+/// `None` for the rows where the variant itself is null.
+pub fn read_variants<R: ParquetReader + Clone>(
+    reader: R,
+    metadata: &RowGroupMetaData,
+    columns: &VariantColumns,
+) -> ParquetResult<Vec<Option<Variant>>> {
+    let data = columns
+        .leaves()
+        .map(|leaf| read_column(reader.clone(), metadata, leaf, None, None))
+        .collect::<ParquetResult<Vec<_>>>()?;
+    let descriptors = columns
+        .leaves()
+        .map(|leaf| metadata.columns[leaf].column_descr().as_ref())
+        .collect::<Vec<_>>();
+    assemble(columns, &descriptors, &data)
+}
+
+/// Rebuilds the values of the variant `columns` from the decoded data of its leaf
+/// columns, given in the order of [`VariantColumns::leaves`].
+pub fn assemble_variants(
+    schema: &SchemaDescriptor,
+    columns: &VariantColumns,
+    data: &[ColumnData],
+) -> ParquetResult<Vec<Option<Variant>>> {
+    let descriptors = columns
+        .leaves()
+        .map(|leaf| schema.column(leaf).as_ref())
+        .collect::<Vec<_>>();
+    assemble(columns, &descriptors, data)
+}
+
+fn assemble(
+    columns: &VariantColumns,
+    descriptors: &[&ColumnDescriptor],
+    data: &[ColumnData],
+) -> ParquetResult<Vec<Option<Variant>>> {
+    if columns.rep_level > 0 {
+        return Err(ParquetError::NotSupported(
+            "Variants nested in a list".into(),
+        ));
+    }
+    if data.len() != descriptors.len() {
+        return Err(ParquetError::InvalidFormat(format!(
+            "Expected the data of {} variant columns, found {}",
+            descriptors.len(),
+            data.len()
+        )));
+    }
+    let mut assembler = Assembler {
+        first_leaf: columns.leaves().start,
+        leaves: descriptors
+            .iter()
+            .zip(data)
+            .map(|(descr, data)| LeafCursor {
+                descr,
+                data,
+                level: 0,
+                value: 0,
+            })
+            .collect(),
+    };
+    let mut variants = vec![];
+    while !assembler.leaf(columns.metadata).is_done() {
+        variants.push(assembler.variant(columns)?);
+    }
+    Ok(variants)
+}
+
+/// The position in the levels and the values of the data of a leaf column.
+struct LeafCursor<'a> {
+    descr: &'a ColumnDescriptor,
+    data: &'a ColumnData,
+    level: usize,
+    value: usize,
+}
+
+impl<'a> LeafCursor<'a> {
+    fn num_levels(&self) -> usize {
+        match &self.data.def_levels {
+            Some(levels) => levels.len(),
+            None => self.data.values.len(),
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.level >= self.num_levels()
+    }
+
+    fn def_level(&self) -> ParquetResult<i16> {
+        if self.is_done() {
+            return Err(invalid("the levels of the columns do not line up"));
+        }
+        Ok(match &self.data.def_levels {
+            Some(levels) => levels[self.level],
+            None => self.descr.max_def_level(),
+        })
+    }
+
+    /// The repetition level of the next entry, 0 if there is none.
+    fn rep_level(&self) -> i16 {
+        match &self.data.rep_levels {
+            Some(levels) => levels.get(self.level).copied().unwrap_or(0),
+            None => 0,
+        }
+    }
+
+    /// Consumes an entry, returning the index of its value if it is not null.
+    fn next(&mut self) -> ParquetResult<Option<usize>> {
+        let is_null = self.def_level()? < self.descr.max_def_level();
+        self.level += 1;
+        if is_null {
+            return Ok(None);
+        }
+        self.value += 1;
+        if self.value > self.data.values.len() {
+            return Err(invalid("the levels of the columns do not line up"));
+        }
+        Ok(Some(self.value - 1))
+    }
+
+    fn next_binary(&mut self) -> ParquetResult<Option<&'a [u8]>> {
+        let data = self.data;
+        let Some(i) = self.next()? else {
+            return Ok(None);
+        };
+        match &data.values {
+            ColumnValues::ByteArray(values) => Ok(Some(values[i].data())),
+            _ => Err(invalid("the metadata and value columns must be BYTE_ARRAY")),
+        }
+    }
+}
+
+struct Assembler<'a> {
+    first_leaf: usize,
+    leaves: Vec<LeafCursor<'a>>,
+}
+
+impl<'a> Assembler<'a> {
+    fn leaf(&mut self, leaf: usize) -> &mut LeafCursor<'a> {
+        &mut self.leaves[leaf - self.first_leaf]
+    }
+
+    /// Consumes an entry of every leaf column of a value that is null or missing.
+    fn skip(&mut self, leaves: std::ops::Range<usize>) -> ParquetResult<()> {
+        for leaf in leaves {
+            self.leaf(leaf).next()?;
+        }
+        Ok(())
+    }
+
+    fn variant(&mut self, columns: &VariantColumns) -> ParquetResult<Option<Variant>> {
+        if self.leaf(columns.metadata).def_level()? < columns.def_level {
+            self.skip(columns.leaves())?;
+            return Ok(None);
+        }
+        let metadata = self
+            .leaf(columns.metadata)
+            .next_binary()?
+            .ok_or_else(|| invalid("the metadata is null"))?;
+        let dictionary = decode_metadata(metadata)?;
+        let value = self.shredded(&columns.value, &dictionary)?;
+        Ok(Some(value.unwrap_or(Variant::Null)))
+    }
+
+    /// Rebuilds a shredded value, `None` if it is missing, e.g. a field absent from an
+    /// object.
+    fn shredded(
+        &mut self,
+        columns: &ShreddedColumns,
+        dictionary: &[&str],
+    ) -> ParquetResult<Option<Variant>> {
+        let value = match columns.value {
+            Some(leaf) => self.leaf(leaf).next_binary()?,
+            None => None,
+        };
+        let typed_value = match &columns.typed_value {
+            Some(typed_value) => self.typed_value(typed_value, dictionary)?,
+            None => None,
+        };
+        let value = value
+            .map(|value| decode_value(dictionary, value, 0))
+            .transpose()?;
+        match (value, typed_value) {
+            (value, None) => Ok(value),
+            (None, typed_value) => Ok(typed_value),
+            // A partially shredded object: the fields that are not shredded are in `value`.
+            (Some(Variant::Object(fields)), Some(Variant::Object(mut typed_fields))) => {
+                typed_fields.extend(fields);
+                Ok(Some(Variant::Object(typed_fields)))
+            }
+            _ => Err(invalid(
+                "both value and typed_value are set for a value that is not an object",
+            )),
+        }
+    }
+
+    fn typed_value(
+        &mut self,
+        columns: &TypedValueColumns,
+        dictionary: &[&str],
+    ) -> ParquetResult<Option<Variant>> {
+        match columns {
+            TypedValueColumns::Primitive(leaf) => {
+                let leaf = self.leaf(*leaf);
+                let (descr, data) = (leaf.descr, leaf.data);
+                leaf.next()?
+                    .map(|i| typed_variant(descr, &data.values, i))
+                    .transpose()
+            }
+            TypedValueColumns::Object { def_level, fields } => {
+                if self.leaf(columns.leaves().start).def_level()? < *def_level {
+                    self.skip(columns.leaves())?;
+                    return Ok(None);
+                }
+                let mut object = vec![];
+                for (name, field) in fields {
+                    if let Some(value) = self.shredded(field, dictionary)? {
+                        object.push((name.clone(), value));
+                    }
+                }
+                Ok(Some(Variant::Object(object)))
+            }
+            TypedValueColumns::Array {
+                def_level,
+                rep_level,
+                element,
+            } => {
+                let first_leaf = columns.leaves().start;
+                let level = self.leaf(first_leaf).def_level()?;
+                if level <= *def_level {
+                    self.skip(columns.leaves())?;
+                    return Ok((level == *def_level).then(|| Variant::Array(vec![])));
+                }
+                let mut array = vec![];
+                loop {
+                    array.push(self.shredded(element, dictionary)?.unwrap_or(Variant::Null));
+                    if self.leaf(first_leaf).rep_level() != *rep_level {
+                        break;
+                    }
+                }
+                Ok(Some(Variant::Array(array)))
+            }
+        }
+    }
+}
+
+/// Converts the `i`th value of a shredded `typed_value` column to a variant.
+fn typed_variant(
+    descr: &ColumnDescriptor,
+    values: &ColumnValues,
+    i: usize,
+) -> ParquetResult<Variant> {
+    let logical_type = descr.logical_type();
+    let decimal_scale =
+        |scale: i32| u8::try_from(scale).map_err(|_| invalid("a decimal scale is out of range"));
+    let not_supported = || {
+        ParquetError::NotSupported(format!(
+            "Shredded variant column of type {:?} {:?}",
+            descr.physical_type(),
+            logical_type
+        ))
+    };
+    Ok(match (values, logical_type) {
+        (ColumnValues::Boolean(values), None) => Variant::Boolean(values[i]),
+        (ColumnValues::Int32(values), Some(LogicalType::Integer { bit_width: 8, .. })) => {
+            Variant::Int8(values[i] as i8)
+        }
+        (ColumnValues::Int32(values), Some(LogicalType::Integer { bit_width: 16, .. })) => {
+            Variant::Int16(values[i] as i16)
+        }
+        (ColumnValues::Int32(values), None | Some(LogicalType::Integer { .. })) => {
+            Variant::Int32(values[i])
+        }
+        (ColumnValues::Int32(values), Some(LogicalType::Date)) => Variant::Date(values[i]),
+        (ColumnValues::Int32(values), Some(LogicalType::Decimal { scale, .. })) => {
+            Variant::Decimal4 {
+                value: values[i],
+                scale: decimal_scale(*scale)?,
+            }
+        }
+        (ColumnValues::Int64(values), None | Some(LogicalType::Integer { .. })) => {
+            Variant::Int64(values[i])
+        }
+        (ColumnValues::Int64(values), Some(LogicalType::Decimal { scale, .. })) => {
+            Variant::Decimal8 {
+                value: values[i],
+                scale: decimal_scale(*scale)?,
+            }
+        }
+        (
+            ColumnValues::Int64(values),
+            Some(LogicalType::Timestamp {
+                is_adjusted_to_utc,
+                unit,
+            }),
+        ) => match (unit, is_adjusted_to_utc) {
+            (TimeUnit::MICROS(_), true) => Variant::TimestampMicros(values[i]),
+            (TimeUnit::MICROS(_), false) => Variant::TimestampNtzMicros(values[i]),
+            (TimeUnit::NANOS(_), true) => Variant::TimestampNanos(values[i]),
+            (TimeUnit::NANOS(_), false) => Variant::TimestampNtzNanos(values[i]),
+            (TimeUnit::MILLIS(_), _) => return Err(not_supported()),
+        },
+        (
+            ColumnValues::Int64(values),
+            Some(LogicalType::Time {
+                is_adjusted_to_utc: false,
+                unit: TimeUnit::MICROS(_),
+            }),
+        ) => Variant::TimeNtzMicros(values[i]),
+        (ColumnValues::Float(values), None) => Variant::Float(values[i]),
+        (ColumnValues::Double(values), None) => Variant::Double(values[i]),
+        (ColumnValues::ByteArray(values), Some(LogicalType::String)) => {
+            let value = std::str::from_utf8(values[i].data())
+                .map_err(|_| invalid("a string is not UTF-8"))?;
+            Variant::String(value.to_string())
+        }
+        (ColumnValues::ByteArray(values), None) => Variant::Binary(values[i].data().to_vec()),
+        (ColumnValues::FixedLenByteArray(values), Some(LogicalType::Uuid)) => Variant::Uuid(
+            values[i]
+                .data()
+                .try_into()
+                .map_err(|_| invalid("a UUID is not 16 bytes"))?,
+        ),
+        (ColumnValues::FixedLenByteArray(values), Some(LogicalType::Decimal { scale, .. })) => {
+            let bytes = values[i].data();
+            if bytes.is_empty() || bytes.len() > 16 {
+                return Err(invalid("a decimal is longer than 16 bytes"));
+            }
+            // Big endian two's complement, sign extended to 16 bytes.
+            let fill = if bytes[0] & 0x80 != 0 { 0xFF } else { 0 };
+            let mut be = [fill; 16];
+            be[16 - bytes.len()..].copy_from_slice(bytes);
+            Variant::Decimal16 {
+                value: i128::from_be_bytes(be),
+                scale: decimal_scale(*scale)?,
+            }
+        }
+        _ => return Err(not_supported()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metadata::types::{ParquetType, TypeInfo};
+    use crate::metadata::{PhysicalType, Repetition};
+    use crate::physical::ByteArray;
+
+    // The dictionary ["a", "b"].
+    const METADATA: &[u8] = &[0x01, 2, 0, 1, 2, b'a', b'b'];
+
+    fn info(name: &str, repetition: Repetition, logical_type: Option<LogicalType>) -> TypeInfo {
+        TypeInfo {
+            name: name.into(),
+            repetition: Some(repetition),
+            converted_type: None,
+            logical_type,
+            id: None,
+        }
+    }
+
+    fn leaf(name: &str, repetition: Repetition, physical: PhysicalType) -> ParquetType {
+        ParquetType::Primitive {
+            info: info(name, repetition, None),
+            physical,
+            type_length: -1,
+            scale: -1,
+            precision: -1,
+        }
+    }
+
+    fn group(name: &str, repetition: Repetition, fields: Vec<ParquetType>) -> ParquetType {
+        ParquetType::Group {
+            info: info(name, repetition, None),
+            fields,
+        }
+    }
+
+    fn binary(values: &[&[u8]]) -> ColumnValues {
+        ColumnValues::ByteArray(values.iter().map(|v| ByteArray::new(v.to_vec())).collect())
+    }
+
+    #[test]
+    fn test_decode() {
+        // {"a": 5, "b": [true, null]}
+        let value = [
+            0x02, 2, 0, 1, 0, 2, 9, // object header, field ids and offsets
+            0x0C, 5, // int8
+            0x03, 2, 0, 1, 2, 0x04, 0x00, // array of true and null
+        ];
+        let expected = Variant::Object(vec![
+            ("a".into(), Variant::Int8(5)),
+            (
+                "b".into(),
+                Variant::Array(vec![Variant::Boolean(true), Variant::Null]),
+            ),
+        ]);
+        assert_eq!(Variant::decode(METADATA, &value).unwrap(), expected);
+        assert!(Variant::decode(METADATA, &value[..8]).is_err());
+    }
+
+    #[test]
+    fn test_decode_nested() {
+        // Arrays of a single element, with 4 byte offsets, around a null.
+        let nest = |depth: usize| {
+            (0..depth).fold(vec![0x00], |inner, _| {
+                let mut value = vec![0x0F, 1, 0, 0, 0, 0];
+                value.extend_from_slice(&(inner.len() as u32).to_le_bytes());
+                value.extend(inner);
+                value
+            })
+        };
+        assert!(Variant::decode(METADATA, &nest(MAX_DEPTH)).is_ok());
+        assert!(matches!(
+            Variant::decode(METADATA, &nest(MAX_DEPTH + 1)),
+            Err(ParquetError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_assemble_shredded() {
+        use Repetition::*;
+        let typed_value = group(
+            "typed_value",
+            Optional,
+            vec![group(
+                "a",
+                Required,
+                vec![
+                    leaf("value", Optional, PhysicalType::ByteArray),
+                    leaf("typed_value", Optional, PhysicalType::Int32),
+                ],
+            )],
+        );
+        let ParquetType::Group { mut info, fields } = group(
+            "v",
+            Optional,
+            vec![
+                leaf("metadata", Required, PhysicalType::ByteArray),
+                leaf("value", Optional, PhysicalType::ByteArray),
+                typed_value,
+            ],
+        ) else {
+            unreachable!()
+        };
+        info.logical_type = Some(LogicalType::Variant {
+            specification_version: None,
+        });
+        let variant = ParquetType::Group { info, fields };
+        let schema = SchemaDescriptor::new(group("schema", Required, vec![variant]));
+
+        let columns = schema.variant_columns().unwrap();
+        assert_eq!(columns.len(), 1);
+        let columns = &columns[0];
+        assert_eq!(columns.leaves(), 0..4);
+
+        let column = |values, def_levels| ColumnData {
+            values,
+            def_levels: Some(def_levels),
+            rep_levels: None,
+            num_rows: 4,
+        };
+        // Rows: null, {"a": 7, "b": "x"} with "b" not shredded, "str", and {}.
+        let data = [
+            column(binary(&[METADATA; 3]), vec![0, 1, 1, 1]),
+            column(
+                binary(&[&[0x02, 1, 1, 0, 2, 0x05, b'x'], &[0x0D, b's', b't', b'r']]),
+                vec![0, 2, 2, 1],
+            ),
+            column(binary(&[]), vec![0, 2, 1, 2]),
+            column(ColumnValues::Int32(vec![7]), vec![0, 3, 1, 2]),
+        ];
+        let variants = assemble_variants(&schema, columns, &data).unwrap();
+        assert_eq!(
+            variants,
+            vec![
+                None,
+                Some(Variant::Object(vec![
+                    ("a".into(), Variant::Int32(7)),
+                    ("b".into(), Variant::String("x".into())),
+                ])),
+                Some(Variant::String("str".into())),
+                Some(Variant::Object(vec![])),
+            ]
+        );
+    }
+}